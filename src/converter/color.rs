@@ -24,6 +24,14 @@ pub fn parse_color(color: &str) -> Result<(u8, u8, u8, u8)> {
             let b = u8::from_str_radix(&color[2..3].repeat(2), 16)?;
             Ok((r, g, b, 255))
         }
+        // RGBA
+        4 => {
+            let r = u8::from_str_radix(&color[0..1].repeat(2), 16)?;
+            let g = u8::from_str_radix(&color[1..2].repeat(2), 16)?;
+            let b = u8::from_str_radix(&color[2..3].repeat(2), 16)?;
+            let a = u8::from_str_radix(&color[3..4].repeat(2), 16)?;
+            Ok((r, g, b, a))
+        }
         // RRGGBB
         6 => {
             let r = u8::from_str_radix(&color[0..2], 16)?;
@@ -44,7 +52,7 @@ pub fn parse_color(color: &str) -> Result<(u8, u8, u8, u8)> {
 }
 
 pub fn normalize_color(fg: &str, bg: &str) -> Result<String> {
-    println!("will normalize color: fg: {}, bg: {}", fg, bg);
+    log::debug!("will normalize color: fg: {}, bg: {}", fg, bg);
     let (fg_r, fg_g, fg_b, fg_a) = parse_color(fg)?;
     let (bg_r, bg_g, bg_b, _) = parse_color(bg)?;
 
@@ -58,7 +66,73 @@ pub fn normalize_color(fg: &str, bg: &str) -> Result<String> {
     Ok(format!("#{:02x}{:02x}{:02x}", r, g, b))
 }
 
-fn rgb_to_hsl(color: &str) -> Result<(f32, f32, f32)> {
+/// 展开为 `#rrggbb`，带透明度时为 `#rrggbbaa`
+pub fn expand_hex(color: &str) -> Result<String> {
+    let (r, g, b, a) = parse_color(color)?;
+    if a == 255 {
+        Ok(format!("#{:02x}{:02x}{:02x}", r, g, b))
+    } else {
+        Ok(format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a))
+    }
+}
+
+/// WCAG 相对亮度
+fn relative_luminance(r: u8, g: u8, b: u8) -> f32 {
+    let channel = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+/// WCAG 对比度，范围 1.0 ~ 21.0，忽略透明度
+pub fn contrast_ratio(fg: &str, bg: &str) -> Result<f32> {
+    let (fg_r, fg_g, fg_b, _) = parse_color(fg)?;
+    let (bg_r, bg_g, bg_b, _) = parse_color(bg)?;
+
+    let fg_l = relative_luminance(fg_r, fg_g, fg_b);
+    let bg_l = relative_luminance(bg_r, bg_g, bg_b);
+    let (lighter, darker) = if fg_l > bg_l {
+        (fg_l, bg_l)
+    } else {
+        (bg_l, fg_l)
+    };
+
+    Ok((lighter + 0.05) / (darker + 0.05))
+}
+
+/// 调整前景色直到与背景色的对比度达到 `min_ratio`
+///
+/// 深色背景上逐步向白色混合，浅色背景上逐步向黑色混合，已满足时原样返回。
+pub fn ensure_contrast(fg: &str, bg: &str, min_ratio: f32) -> Result<String> {
+    if contrast_ratio(fg, bg)? >= min_ratio {
+        return Ok(fg.to_string());
+    }
+
+    let target = if is_dark_color(bg) {
+        "#ffffff"
+    } else {
+        "#000000"
+    };
+    for step in 1..=20 {
+        let alpha = (255.0 * (1.0 - step as f32 / 20.0)).round() as u8;
+        let (r, g, b, _) = parse_color(fg)?;
+        let candidate = format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, alpha);
+        let mixed = normalize_color(&candidate, target)?;
+        if contrast_ratio(&mixed, bg)? >= min_ratio {
+            return Ok(mixed);
+        }
+    }
+
+    Ok(target.to_string())
+}
+
+/// 转换为 HSL，色相范围 0 ~ 360，饱和度与亮度范围 0 ~ 1
+pub fn rgb_to_hsl(color: &str) -> Result<(f32, f32, f32)> {
     let (r, g, b, _) = parse_color(color)?;
 
     let r_norm = r as f32 / 255.0;
@@ -80,7 +154,7 @@ fn rgb_to_hsl(color: &str) -> Result<(f32, f32, f32)> {
     let hue = if delta == 0.0 {
         0.0
     } else if max == r_norm {
-        60.0 * (((g_norm - b_norm) / delta) % 6.0)
+        60.0 * ((g_norm - b_norm) / delta).rem_euclid(6.0)
     } else if max == g_norm {
         60.0 * ((b_norm - r_norm) / delta + 2.0)
     } else {
@@ -0,0 +1,422 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// 链接最多追踪的层数
+const MAX_LINK_DEPTH: usize = 8;
+
+// Emacs face 映射 (face, 属性, (高亮组, 高亮属性))
+// 属性为 fg、bg 或 underline (波浪下划线颜色)，同一属性后面的条目覆盖前面的
+// fg 同时带上高亮组的粗体、斜体、下划线与删除线
+static EMACS_FACES: &[(&str, &str, (&str, &str))] = &[
+    // 界面
+    ("default", "fg", ("Normal", "fg")),
+    ("default", "bg", ("Normal", "bg")),
+    ("cursor", "bg", ("Cursor", "fg")),
+    ("region", "fg", ("Visual", "fg")),
+    ("region", "bg", ("Visual", "bg")),
+    ("hl-line", "bg", ("CursorLine", "bg")),
+    ("fringe", "bg", ("SignColumn", "bg")),
+    ("line-number", "fg", ("LineNr", "fg")),
+    ("line-number-current-line", "fg", ("CursorLineNr", "fg")),
+    ("mode-line", "fg", ("StatusLine", "fg")),
+    ("mode-line", "bg", ("StatusLine", "bg")),
+    ("mode-line-inactive", "fg", ("StatusLineNC", "fg")),
+    ("mode-line-inactive", "bg", ("StatusLineNC", "bg")),
+    ("header-line", "fg", ("TabLine", "fg")),
+    ("header-line", "bg", ("TabLine", "bg")),
+    ("vertical-border", "fg", ("VertSplit", "fg")),
+    ("vertical-border", "fg", ("WinSeparator", "fg")),
+    ("minibuffer-prompt", "fg", ("Keyword", "fg")),
+    ("link", "fg", ("Underlined", "fg")),
+    ("isearch", "fg", ("IncSearch", "fg")),
+    ("isearch", "bg", ("IncSearch", "bg")),
+    ("lazy-highlight", "bg", ("Search", "bg")),
+    ("match", "bg", ("Search", "bg")),
+    ("show-paren-match", "fg", ("MatchParen", "fg")),
+    ("show-paren-match", "bg", ("MatchParen", "bg")),
+    ("whitespace-space", "fg", ("Whitespace", "fg")),
+    ("whitespace-tab", "fg", ("Whitespace", "fg")),
+    ("error", "fg", ("Error", "fg")),
+    ("warning", "fg", ("WarningMsg", "fg")),
+    ("success", "fg", ("MoreMsg", "fg")),
+    // font-lock
+    ("font-lock-comment-face", "fg", ("Comment", "fg")),
+    ("font-lock-comment-delimiter-face", "fg", ("Comment", "fg")),
+    ("font-lock-doc-face", "fg", ("Comment", "fg")),
+    ("font-lock-doc-face", "fg", ("SpecialComment", "fg")),
+    ("font-lock-keyword-face", "fg", ("Keyword", "fg")),
+    ("font-lock-builtin-face", "fg", ("Special", "fg")),
+    ("font-lock-function-name-face", "fg", ("Function", "fg")),
+    ("font-lock-function-call-face", "fg", ("Function", "fg")),
+    ("font-lock-variable-name-face", "fg", ("Identifier", "fg")),
+    ("font-lock-variable-use-face", "fg", ("Identifier", "fg")),
+    ("font-lock-property-name-face", "fg", ("Identifier", "fg")),
+    ("font-lock-type-face", "fg", ("Type", "fg")),
+    ("font-lock-constant-face", "fg", ("Constant", "fg")),
+    ("font-lock-number-face", "fg", ("Number", "fg")),
+    ("font-lock-string-face", "fg", ("String", "fg")),
+    ("font-lock-escape-face", "fg", ("SpecialChar", "fg")),
+    ("font-lock-preprocessor-face", "fg", ("Macro", "fg")),
+    ("font-lock-operator-face", "fg", ("Operator", "fg")),
+    ("font-lock-delimiter-face", "fg", ("Delimiter", "fg")),
+    ("font-lock-bracket-face", "fg", ("Delimiter", "fg")),
+    ("font-lock-warning-face", "fg", ("WarningMsg", "fg")),
+    // tree-sitter
+    ("tree-sitter-hl-face:comment", "fg", ("@comment", "fg")),
+    ("tree-sitter-hl-face:doc", "fg", ("SpecialComment", "fg")),
+    ("tree-sitter-hl-face:keyword", "fg", ("@keyword", "fg")),
+    ("tree-sitter-hl-face:function", "fg", ("@function", "fg")),
+    (
+        "tree-sitter-hl-face:function.call",
+        "fg",
+        ("@function.call", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:function.builtin",
+        "fg",
+        ("@function.builtin", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:function.macro",
+        "fg",
+        ("@function.macro", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:method",
+        "fg",
+        ("@function.method", "fg"),
+    ),
+    ("tree-sitter-hl-face:type", "fg", ("@type", "fg")),
+    (
+        "tree-sitter-hl-face:type.builtin",
+        "fg",
+        ("@type.builtin", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:constructor",
+        "fg",
+        ("@constructor", "fg"),
+    ),
+    ("tree-sitter-hl-face:constant", "fg", ("@constant", "fg")),
+    (
+        "tree-sitter-hl-face:constant.builtin",
+        "fg",
+        ("@constant.builtin", "fg"),
+    ),
+    ("tree-sitter-hl-face:number", "fg", ("@number", "fg")),
+    ("tree-sitter-hl-face:string", "fg", ("@string", "fg")),
+    ("tree-sitter-hl-face:escape", "fg", ("@string.escape", "fg")),
+    ("tree-sitter-hl-face:variable", "fg", ("@variable", "fg")),
+    (
+        "tree-sitter-hl-face:variable.builtin",
+        "fg",
+        ("@variable.builtin", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:variable.parameter",
+        "fg",
+        ("@variable.parameter", "fg"),
+    ),
+    ("tree-sitter-hl-face:property", "fg", ("@property", "fg")),
+    ("tree-sitter-hl-face:operator", "fg", ("@operator", "fg")),
+    (
+        "tree-sitter-hl-face:punctuation",
+        "fg",
+        ("@punctuation", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:punctuation.bracket",
+        "fg",
+        ("@punctuation.bracket", "fg"),
+    ),
+    (
+        "tree-sitter-hl-face:punctuation.delimiter",
+        "fg",
+        ("@punctuation.delimiter", "fg"),
+    ),
+    ("tree-sitter-hl-face:tag", "fg", ("@tag", "fg")),
+    ("tree-sitter-hl-face:attribute", "fg", ("@attribute", "fg")),
+    ("tree-sitter-hl-face:label", "fg", ("@label", "fg")),
+    // diff / diff-hl / magit
+    ("diff-added", "fg", ("diffAdded", "fg")),
+    ("diff-removed", "fg", ("diffRemoved", "fg")),
+    ("diff-changed", "fg", ("diffChanged", "fg")),
+    ("diff-hl-insert", "fg", ("GitSignsAdd", "fg")),
+    ("diff-hl-change", "fg", ("GitSignsChange", "fg")),
+    ("diff-hl-delete", "fg", ("GitSignsDelete", "fg")),
+    ("magit-diff-added", "fg", ("diffAdded", "fg")),
+    ("magit-diff-removed", "fg", ("diffRemoved", "fg")),
+    ("magit-diff-added-highlight", "fg", ("diffAdded", "fg")),
+    ("magit-diff-removed-highlight", "fg", ("diffRemoved", "fg")),
+    ("magit-section-highlight", "bg", ("CursorLine", "bg")),
+    // 补全
+    ("company-tooltip", "fg", ("Pmenu", "fg")),
+    ("company-tooltip", "bg", ("Pmenu", "bg")),
+    ("company-tooltip-selection", "bg", ("PmenuSel", "bg")),
+    ("corfu-default", "fg", ("Pmenu", "fg")),
+    ("corfu-default", "bg", ("Pmenu", "bg")),
+    ("corfu-current", "bg", ("PmenuSel", "bg")),
+    ("vertico-current", "bg", ("PmenuSel", "bg")),
+    // 诊断与 LSP
+    ("flymake-error", "underline", ("Error", "fg")),
+    ("flymake-warning", "underline", ("WarningMsg", "fg")),
+    ("flymake-note", "underline", ("MoreMsg", "fg")),
+    ("flycheck-error", "underline", ("Error", "fg")),
+    ("flycheck-warning", "underline", ("WarningMsg", "fg")),
+    ("flycheck-info", "underline", ("MoreMsg", "fg")),
+    (
+        "eglot-highlight-symbol-face",
+        "bg",
+        ("LspReferenceText", "bg"),
+    ),
+    (
+        "lsp-face-highlight-textual",
+        "bg",
+        ("LspReferenceText", "bg"),
+    ),
+    ("lsp-face-highlight-read", "bg", ("LspReferenceRead", "bg")),
+    (
+        "lsp-face-highlight-write",
+        "bg",
+        ("LspReferenceWrite", "bg"),
+    ),
+    // 其他常用插件
+    ("tab-bar", "bg", ("TabLineFill", "bg")),
+    ("tab-bar-tab", "fg", ("TabLineSel", "fg")),
+    ("tab-bar-tab", "bg", ("TabLineSel", "bg")),
+    ("tab-bar-tab-inactive", "fg", ("TabLine", "fg")),
+    ("tab-bar-tab-inactive", "bg", ("TabLine", "bg")),
+    ("which-key-key-face", "fg", ("WhichKey", "fg")),
+    (
+        "which-key-group-description-face",
+        "fg",
+        ("WhichKeyGroup", "fg"),
+    ),
+    (
+        "which-key-command-description-face",
+        "fg",
+        ("WhichKeyDesc", "fg"),
+    ),
+    (
+        "which-key-separator-face",
+        "fg",
+        ("WhichKeySeparator", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-1-face",
+        "fg",
+        ("RainbowDelimiterRed", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-2-face",
+        "fg",
+        ("RainbowDelimiterYellow", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-3-face",
+        "fg",
+        ("RainbowDelimiterBlue", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-4-face",
+        "fg",
+        ("RainbowDelimiterOrange", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-5-face",
+        "fg",
+        ("RainbowDelimiterGreen", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-6-face",
+        "fg",
+        ("RainbowDelimiterViolet", "fg"),
+    ),
+    (
+        "rainbow-delimiters-depth-7-face",
+        "fg",
+        ("RainbowDelimiterCyan", "fg"),
+    ),
+];
+
+pub struct EmacsGenerator<'a> {
+    theme: &'a ThemeConfig,
+    highlights: &'a HashMap<String, HighlightGroup>,
+    // deftheme 名称，需与文件名 `<name>-theme.el` 一致
+    name: String,
+}
+
+impl<'a> EmacsGenerator<'a> {
+    pub fn new(
+        theme: &'a ThemeConfig,
+        highlights: &'a HashMap<String, HighlightGroup>,
+        name: &str,
+    ) -> Self {
+        Self {
+            theme,
+            highlights,
+            name: name.replace('_', "-"),
+        }
+    }
+
+    /// 文件名 `<name>-theme.el`
+    pub fn file_name(&self) -> String {
+        format!("{}-theme.el", self.name)
+    }
+
+    /// 生成 `deftheme` 主题文件
+    pub fn generate(&self) -> Result<String> {
+        // face 按首次出现的顺序输出，属性按 Emacs 的常用顺序排列
+        let mut faces: Vec<(&str, FaceSpec)> = Vec::new();
+        for &(face, attr, (group, group_attr)) in EMACS_FACES {
+            let Some(highlight) = self.resolve(group) else {
+                continue;
+            };
+            let value = match group_attr {
+                "fg" => &highlight.fg,
+                "bg" => &highlight.bg,
+                _ => &highlight.sp,
+            };
+            let Some(value) = value.as_deref().and_then(|value| self.color(value)) else {
+                continue;
+            };
+
+            let spec = match faces.iter_mut().find(|(name, _)| *name == face) {
+                Some((_, spec)) => spec,
+                None => {
+                    faces.push((face, FaceSpec::default()));
+                    &mut faces.last_mut().unwrap().1
+                }
+            };
+            match attr {
+                "fg" => {
+                    spec.foreground = Some(value);
+                    spec.bold = highlight.bold.unwrap_or(false);
+                    spec.italic = highlight.italic.unwrap_or(false);
+                    spec.strikethrough = highlight.strikethrough.unwrap_or(false);
+                    spec.underline = if highlight.undercurl.unwrap_or(false) {
+                        let color = highlight.sp.as_deref().and_then(|sp| self.color(sp));
+                        Some(wave_underline(color.as_deref()))
+                    } else if highlight.underline.unwrap_or(false) {
+                        Some("t".to_string())
+                    } else {
+                        None
+                    };
+                }
+                "bg" => spec.background = Some(value),
+                _ => spec.underline = Some(wave_underline(Some(&value))),
+            }
+        }
+
+        let mut content = String::new();
+        content.push_str(&format!(
+            ";;; {} --- {} -*- lexical-binding: t; -*-\n\n",
+            self.file_name(),
+            self.theme.name
+        ));
+        content.push_str(";;; Commentary:\n");
+        content.push_str(&format!(
+            ";; Emacs theme converted from the VSCode theme \"{}\".\n\n",
+            self.theme.name
+        ));
+        content.push_str(";;; Code:\n\n");
+
+        content.push_str(&format!(
+            "(deftheme {}\n  \"{}, converted from VSCode.\")\n\n",
+            self.name,
+            escape(&self.theme.name)
+        ));
+
+        content.push_str("(custom-theme-set-faces\n");
+        content.push_str(&format!(" '{}", self.name));
+        for (face, spec) in &faces {
+            content.push_str(&format!("\n '({} ((t ({}))))", face, spec.format()));
+        }
+        content.push_str(")\n\n");
+
+        content.push_str(";;;###autoload\n");
+        content.push_str("(when load-file-name\n");
+        content.push_str("  (add-to-list 'custom-theme-load-path\n");
+        content.push_str(
+            "               (file-name-as-directory (file-name-directory load-file-name))))\n\n",
+        );
+        content.push_str(&format!("(provide-theme '{})\n\n", self.name));
+        content.push_str(&format!(";;; {} ends here\n", self.file_name()));
+
+        Ok(content)
+    }
+
+    /// 取高亮组，链接到其他组时沿链接查找
+    fn resolve(&self, group: &str) -> Option<&HighlightGroup> {
+        let mut highlight = self.highlights.get(group)?;
+        for _ in 0..MAX_LINK_DEPTH {
+            match &highlight.link {
+                Some(link) => highlight = self.highlights.get(link)?,
+                None => break,
+            }
+        }
+        Some(highlight)
+    }
+
+    /// 与编辑器背景混合去掉透明度
+    fn color(&self, value: &str) -> Option<String> {
+        let editor_bg = self
+            .theme
+            .colors
+            .get("editor.background")
+            .map(String::as_str)
+            .unwrap_or(value);
+        color::normalize_color(value, editor_bg).ok()
+    }
+}
+
+#[derive(Default)]
+struct FaceSpec {
+    foreground: Option<String>,
+    background: Option<String>,
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    // `t` 或 `(:style wave :color "...")`
+    underline: Option<String>,
+}
+
+impl FaceSpec {
+    fn format(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(fg) = &self.foreground {
+            attrs.push(format!(":foreground \"{}\"", fg));
+        }
+        if let Some(bg) = &self.background {
+            attrs.push(format!(":background \"{}\"", bg));
+        }
+        if self.bold {
+            attrs.push(":weight bold".to_string());
+        }
+        if self.italic {
+            attrs.push(":slant italic".to_string());
+        }
+        if let Some(underline) = &self.underline {
+            attrs.push(format!(":underline {}", underline));
+        }
+        if self.strikethrough {
+            attrs.push(":strike-through t".to_string());
+        }
+        attrs.join(" ")
+    }
+}
+
+fn wave_underline(color: Option<&str>) -> String {
+    match color {
+        Some(color) => format!("(:style wave :color \"{}\")", color),
+        None => "(:style wave)".to_string(),
+    }
+}
+
+/// Elisp 字符串转义
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,199 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use toml::{Table, Value};
+
+use super::color;
+use super::palette::Palette;
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// Helix 作用域映射 (作用域, 属性, (高亮组, 高亮属性))
+// 属性为 fg、bg 或 underline (波浪下划线颜色)，同一属性后面的条目覆盖前面的
+static HELIX_SCOPES: &[(&str, &str, (&str, &str))] = &[
+    // ui
+    ("ui.background", "bg", ("Normal", "bg")),
+    ("ui.text", "fg", ("Normal", "fg")),
+    ("ui.cursor", "fg", ("Normal", "bg")),
+    ("ui.cursor", "fg", ("Cursor", "bg")),
+    ("ui.cursor", "bg", ("Cursor", "fg")),
+    ("ui.cursor.match", "fg", ("MatchParen", "fg")),
+    ("ui.cursor.match", "bg", ("MatchParen", "bg")),
+    ("ui.cursorline.primary", "bg", ("CursorLine", "bg")),
+    ("ui.selection", "fg", ("Visual", "fg")),
+    ("ui.selection", "bg", ("Visual", "bg")),
+    ("ui.linenr", "fg", ("LineNr", "fg")),
+    ("ui.linenr.selected", "fg", ("CursorLineNr", "fg")),
+    ("ui.gutter", "bg", ("SignColumn", "bg")),
+    ("ui.statusline", "fg", ("StatusLine", "fg")),
+    ("ui.statusline", "bg", ("StatusLine", "bg")),
+    ("ui.statusline.inactive", "fg", ("StatusLineNC", "fg")),
+    ("ui.statusline.inactive", "bg", ("StatusLineNC", "bg")),
+    ("ui.bufferline", "fg", ("TabLine", "fg")),
+    ("ui.bufferline", "bg", ("TabLine", "bg")),
+    ("ui.bufferline.active", "fg", ("TabLineSel", "fg")),
+    ("ui.bufferline.active", "bg", ("TabLineSel", "bg")),
+    ("ui.bufferline.background", "bg", ("TabLineFill", "bg")),
+    ("ui.popup", "fg", ("Pmenu", "fg")),
+    ("ui.popup", "bg", ("Pmenu", "bg")),
+    ("ui.help", "fg", ("Pmenu", "fg")),
+    ("ui.help", "bg", ("Pmenu", "bg")),
+    ("ui.menu", "fg", ("Pmenu", "fg")),
+    ("ui.menu", "bg", ("Pmenu", "bg")),
+    ("ui.menu.selected", "bg", ("PmenuSel", "bg")),
+    ("ui.virtual.whitespace", "fg", ("Whitespace", "fg")),
+    ("ui.virtual.indent-guide", "fg", ("IblIndent", "fg")),
+    ("ui.highlight", "bg", ("LspReferenceText", "bg")),
+    // syntax
+    ("comment", "fg", ("Comment", "fg")),
+    (
+        "comment.block.documentation",
+        "fg",
+        ("SpecialComment", "fg"),
+    ),
+    ("keyword", "fg", ("Keyword", "fg")),
+    ("keyword.control", "fg", ("Keyword", "fg")),
+    ("keyword.control", "fg", ("Statement", "fg")),
+    ("keyword.storage", "fg", ("StorageClass", "fg")),
+    ("function", "fg", ("Function", "fg")),
+    ("function.method", "fg", ("Function", "fg")),
+    ("function.macro", "fg", ("Macro", "fg")),
+    ("type", "fg", ("Type", "fg")),
+    ("constructor", "fg", ("Type", "fg")),
+    ("constructor", "fg", ("Structure", "fg")),
+    ("constant", "fg", ("Constant", "fg")),
+    ("constant.numeric", "fg", ("Number", "fg")),
+    ("constant.character", "fg", ("Character", "fg")),
+    ("constant.character.escape", "fg", ("SpecialChar", "fg")),
+    ("string", "fg", ("String", "fg")),
+    ("string.special", "fg", ("Special", "fg")),
+    ("variable", "fg", ("Identifier", "fg")),
+    ("operator", "fg", ("Operator", "fg")),
+    ("punctuation", "fg", ("Delimiter", "fg")),
+    ("tag", "fg", ("Tag", "fg")),
+    ("special", "fg", ("Special", "fg")),
+    ("markup.heading", "fg", ("Title", "fg")),
+    ("markup.bold", "fg", ("Bold", "fg")),
+    ("markup.italic", "fg", ("Italic", "fg")),
+    ("markup.link.url", "fg", ("Underlined", "fg")),
+    // diff
+    ("diff.plus", "fg", ("diffAdded", "fg")),
+    ("diff.minus", "fg", ("diffRemoved", "fg")),
+    ("diff.delta", "fg", ("diffChanged", "fg")),
+    // diagnostics
+    ("error", "fg", ("Error", "fg")),
+    ("warning", "fg", ("WarningMsg", "fg")),
+    ("info", "fg", ("MoreMsg", "fg")),
+    ("hint", "fg", ("MoreMsg", "fg")),
+    ("diagnostic.error", "underline", ("Error", "fg")),
+    ("diagnostic.warning", "underline", ("WarningMsg", "fg")),
+    ("diagnostic.info", "underline", ("MoreMsg", "fg")),
+    ("diagnostic.hint", "underline", ("MoreMsg", "fg")),
+];
+
+pub struct HelixGenerator<'a> {
+    theme: &'a ThemeConfig,
+    highlights: &'a HashMap<String, HighlightGroup>,
+    palette: Palette,
+}
+
+impl<'a> HelixGenerator<'a> {
+    pub fn new(theme: &'a ThemeConfig, highlights: &'a HashMap<String, HighlightGroup>) -> Self {
+        Self {
+            theme,
+            highlights,
+            palette: Palette::new(theme, highlights),
+        }
+    }
+
+    /// 生成 `themes/<name>.toml`
+    pub fn generate(&self) -> Result<String> {
+        // 作用域按首次出现的顺序输出
+        let mut scopes: Vec<(&str, Table)> = Vec::new();
+        for &(scope, attr, (group, group_attr)) in HELIX_SCOPES {
+            let Some(highlight) = self.highlights.get(group) else {
+                continue;
+            };
+            let value = match group_attr {
+                "fg" => &highlight.fg,
+                "bg" => &highlight.bg,
+                _ => &highlight.sp,
+            };
+            let Some(value) = value.as_deref().and_then(|value| self.color_name(value)) else {
+                continue;
+            };
+
+            let style = match scopes.iter_mut().find(|(name, _)| *name == scope) {
+                Some((_, style)) => style,
+                None => {
+                    scopes.push((scope, Table::new()));
+                    &mut scopes.last_mut().unwrap().1
+                }
+            };
+            match attr {
+                "underline" => {
+                    let mut underline = Table::new();
+                    underline.insert("color".to_string(), Value::String(value));
+                    underline.insert("style".to_string(), Value::String("curl".to_string()));
+                    style.insert("underline".to_string(), Value::Table(underline));
+                }
+                _ => {
+                    style.insert(attr.to_string(), Value::String(value));
+                    if attr == "fg" {
+                        let modifiers = modifiers(highlight);
+                        if !modifiers.is_empty() {
+                            style.insert("modifiers".to_string(), Value::Array(modifiers));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# Helix theme converted from VSCode Theme\n\n");
+
+        for (scope, style) in scopes {
+            content.push_str(&format!("\"{}\" = {}\n", scope, Value::Table(style)));
+        }
+
+        content.push_str("\n[palette]\n");
+        for (name, value) in self.palette.entries() {
+            content.push_str(&format!("{} = \"{}\"\n", name, value));
+        }
+
+        Ok(content)
+    }
+
+    /// 混合透明度后取调色板中的名称
+    fn color_name(&self, value: &str) -> Option<String> {
+        let editor_bg = self
+            .theme
+            .colors
+            .get("editor.background")
+            .map(String::as_str)
+            .unwrap_or(value);
+        let normalized = color::normalize_color(value, editor_bg).ok()?;
+        Some(
+            self.palette
+                .name_of(&normalized)
+                .map(str::to_string)
+                .unwrap_or(normalized),
+        )
+    }
+}
+
+fn modifiers(highlight: &HighlightGroup) -> Vec<Value> {
+    let mut modifiers = Vec::new();
+    if highlight.bold.unwrap_or(false) {
+        modifiers.push(Value::String("bold".to_string()));
+    }
+    if highlight.italic.unwrap_or(false) {
+        modifiers.push(Value::String("italic".to_string()));
+    }
+    if highlight.underline.unwrap_or(false) {
+        modifiers.push(Value::String("underlined".to_string()));
+    }
+    if highlight.strikethrough.unwrap_or(false) {
+        modifiers.push(Value::String("crossed_out".to_string()));
+    }
+    modifiers
+}
@@ -1,72 +1,81 @@
-use anyhow::Result;
-use std::collections::HashMap;
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
 
-use super::color;
+use super::palette::Palette;
+use super::{color, mapping};
 use crate::types::{HighlightGroup, ThemeConfig};
 
 pub struct LuaGenerator<'a> {
     theme: &'a ThemeConfig,
     highlights: &'a HashMap<String, HighlightGroup>,
+    integrations: &'a [(String, Vec<String>)],
+    palette: Palette,
+    // `M.options.transparent` 的默认值
+    transparent: bool,
 }
 
 impl<'a> LuaGenerator<'a> {
-    pub fn new(theme: &'a ThemeConfig, highlights: &'a HashMap<String, HighlightGroup>) -> Self {
-        Self { theme, highlights }
+    pub fn new(
+        theme: &'a ThemeConfig,
+        highlights: &'a HashMap<String, HighlightGroup>,
+        integrations: &'a [(String, Vec<String>)],
+    ) -> Self {
+        Self {
+            theme,
+            highlights,
+            integrations,
+            palette: Palette::new(theme, highlights),
+            transparent: false,
+        }
+    }
+
+    /// 默认开启透明背景，背景色仍写入高亮表，由运行时清除
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
     }
 
     pub fn generate(&self) -> Result<String> {
-        println!("[generate_modular] theme: {:?}", self.theme.name);
+        log::debug!("Generating Lua theme: {}", self.theme.name);
         let mut content = String::new();
 
         content.push_str(&format!("-- Name: {}\n\n", self.theme.name));
 
         content.push_str("local M = {}\n\n");
 
-        content.push_str("function M.setup()\n");
-
-        content.push_str("  vim.cmd('hi clear')\n");
-        content.push_str("  if vim.fn.exists('syntax_on') then\n");
-        content.push_str("    vim.cmd('syntax reset')\n");
-        content.push_str("  end\n");
+        content.push_str("-- Color palette\n");
+        content.push_str("M.palette = {\n");
+        content.push_str(&self.format_palette_fields());
+        content.push_str("}\n\n");
 
-        let is_dark = self.is_dark_theme();
-        let theme_name = sanitize_name(&self.theme.name);
-        content.push_str(&format!("  vim.g.colors_name = \"{}\"\n", theme_name));
-        content.push_str("  vim.o.termguicolors = true\n");
-        content.push_str(&format!(
-            "  vim.o.background = \"{}\"\n\n",
-            if is_dark { "dark" } else { "light" }
-        ));
-
-        content.push_str("  -- Define highlight groups\n");
-        content.push_str("  local highlights = {\n");
+        content.push_str(&self.generate_enabled());
+        content.push_str(&options_block(self.transparent));
+        content.push('\n');
+        content.push_str(&option_groups_block());
 
+        content.push_str("-- Highlight groups built from the palette\n");
+        content.push_str("local function base_groups(p)\n");
+        content.push_str("  return {\n");
+        let integration_groups: HashSet<&String> = self
+            .integrations
+            .iter()
+            .flat_map(|(_, groups)| groups)
+            .collect();
         for (group, highlight) in self.highlights {
-            content.push_str(&self.format_highlight_table(group, highlight));
+            if !integration_groups.contains(group) {
+                content.push_str(&format!(
+                    "    {},\n",
+                    self.format_highlight_table(group, highlight)?
+                ));
+            }
         }
-
-        content.push_str("  }\n\n");
-
-        content.push_str("  -- Set highlights\n");
-        content.push_str("  for group, styles in pairs(highlights) do\n");
-        content.push_str("    M.highlight(group, styles)\n");
-        content.push_str("  end\n");
+        content.push_str("  }\n");
         content.push_str("end\n\n");
 
-        content.push_str("-- Helper function to set highlights\n");
-        content.push_str("function M.highlight(group, styles)\n");
-        content.push_str("  vim.api.nvim_set_hl(0, group, {\n");
-        content.push_str("    fg = styles.fg,\n");
-        content.push_str("    bg = styles.bg,\n");
-        content.push_str("    sp = styles.sp,\n");
-        content.push_str("    bold = styles.bold,\n");
-        content.push_str("    italic = styles.italic,\n");
-        content.push_str("    underline = styles.underline,\n");
-        content.push_str("    undercurl = styles.undercurl,\n");
-        content.push_str("    strikethrough = styles.strikethrough,\n");
-        content.push_str("    link = styles.link,\n");
-        content.push_str("  })\n");
-        content.push_str("end\n\n");
+        content.push_str(&self.generate_integrations()?);
+
+        let theme_name = sanitize_name(&self.theme.name);
+        content.push_str(&runtime_functions(&theme_name, self.is_dark_theme()));
 
         // content.push_str("-- Set terminal colors\n");
         // content.push_str("function M.set_terminal_colors()\n");
@@ -95,36 +104,115 @@ impl<'a> LuaGenerator<'a> {
         Ok(content)
     }
 
-    fn format_highlight_table(&self, group: &str, highlight: &HighlightGroup) -> String {
-        println!("[format_highlight_table] group: {:?}", group);
-        let bg_color = self.theme.colors.get("editor.background").unwrap();
+    /// 各插件集成的高亮表，按调色板生成
+    fn generate_integrations(&self) -> Result<String> {
+        let mut content = String::new();
+
+        content.push_str("-- Plugin integrations\n");
+        content.push_str("local integrations = {\n");
+        for (name, groups) in self.integrations {
+            content.push_str(&format!("  [\"{}\"] = function(p)\n", name));
+            content.push_str("    return {\n");
+            for group in groups {
+                if let Some(highlight) = self.highlights.get(group) {
+                    content.push_str(&format!(
+                        "      {},\n",
+                        self.format_highlight_table(group, highlight)?
+                    ));
+                }
+            }
+            content.push_str("    }\n");
+            content.push_str("  end,\n");
+        }
+        content.push_str("}\n\n");
+
+        content.push_str("local function integration_groups(name, p)\n");
+        content.push_str("  return integrations[name](p)\n");
+        content.push_str("end\n\n");
+
+        Ok(content)
+    }
+
+    /// 默认启用的插件集成
+    pub(super) fn generate_enabled(&self) -> String {
+        let mut content = String::new();
+        content.push_str("-- Plugin integrations\n");
+        content.push_str("M.enabled = {\n");
+        for (name, _) in self.integrations {
+            content.push_str(&format!("  [\"{}\"] = true,\n", name));
+        }
+        content.push_str("}\n\n");
+        content
+    }
+
+    /// 独立的 `palette.lua` 模块，返回调色板表
+    pub fn generate_palette(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("-- Name: {}\n", self.theme.name));
+        content.push_str("-- Color palette\n\n");
+        content.push_str("return {\n");
+        content.push_str(&self.format_palette_fields());
+        content.push_str("}\n");
+        content
+    }
+
+    /// 调色板表项，每行一个 `name = "#rrggbb",`
+    pub(super) fn format_palette_fields(&self) -> String {
+        let mut content = String::new();
+        for (name, value) in self.palette.entries() {
+            content.push_str(&format!("  {} = \"{}\",\n", name, value));
+        }
+        content
+    }
+
+    /// 调色板中的颜色引用为 `p.<name>`，其余输出字面量
+    fn color_value(&self, value: &str) -> String {
+        match self.palette.name_of(value) {
+            Some(name) => format!("p.{}", name),
+            None => format!("\"{}\"", value),
+        }
+    }
+
+    /// 单个高亮组的 Lua 表项，如 `Normal = { fg = "#ffffff" }`，不含缩进与结尾逗号
+    pub(super) fn format_highlight_table(
+        &self,
+        group: &str,
+        highlight: &HighlightGroup,
+    ) -> Result<String> {
+        let bg_color = self
+            .theme
+            .colors
+            .get("editor.background")
+            .context("Missing editor.background, needed to blend transparent colors")?;
         let mut parts = Vec::new();
 
-        // consider deal link
-        // if let Some(link) = &highlight.link {
-        //     return format!("    {} = {{ link = \"{}\" }},\n", group, link);
-        // }
+        // `@` 等非标识符字符的组名需要写成 ["..."]
+        let key = if is_lua_identifier(group) {
+            group.to_string()
+        } else {
+            format!("[\"{}\"]", group)
+        };
 
-        if let Some(fg) = &highlight.fg {
-            if let Ok(normalized_fg) = color::normalize_color(fg, bg_color) {
-                parts.push(format!("fg = \"{}\"", normalized_fg));
-            }
+        if let Some(link) = &highlight.link {
+            return Ok(format!("{} = {{ link = \"{}\" }}", key, link));
         }
 
-        if let Some(bg) = &highlight.bg {
-            if let Ok(normalized_bg) = color::normalize_color(bg, bg_color) {
-                println!(
-                    "[format_highlight_table] normalized_bg: {:?}",
-                    normalized_bg
-                );
-                parts.push(format!("bg = \"{}\"", normalized_bg));
-            }
+        if let Some(fg) = &highlight.fg
+            && let Ok(normalized_fg) = color::normalize_color(fg, bg_color)
+        {
+            parts.push(format!("fg = {}", self.color_value(&normalized_fg)));
         }
 
-        if let Some(sp) = &highlight.sp {
-            if let Ok(normalized_sp) = color::normalize_color(sp, bg_color) {
-                parts.push(format!("sp = \"{}\"", normalized_sp));
-            }
+        if let Some(bg) = &highlight.bg
+            && let Ok(normalized_bg) = color::normalize_color(bg, bg_color)
+        {
+            parts.push(format!("bg = {}", self.color_value(&normalized_bg)));
+        }
+
+        if let Some(sp) = &highlight.sp
+            && let Ok(normalized_sp) = color::normalize_color(sp, bg_color)
+        {
+            parts.push(format!("sp = {}", self.color_value(&normalized_sp)));
         }
 
         if highlight.bold.unwrap_or(false) {
@@ -143,18 +231,10 @@ impl<'a> LuaGenerator<'a> {
             parts.push("strikethrough = true".to_string());
         }
 
-        format!(
-            "    {} = {{ {} }},\n",
-            if group.contains('@') {
-                format!("[\"{}\"]", group)
-            } else {
-                group.to_string()
-            },
-            parts.join(", ")
-        )
+        Ok(format!("{} = {{ {} }}", key, parts.join(", ")))
     }
 
-    fn is_dark_theme(&self) -> bool {
+    pub(super) fn is_dark_theme(&self) -> bool {
         if self.theme.theme_type == "dark" {
             return true;
         }
@@ -167,6 +247,222 @@ impl<'a> LuaGenerator<'a> {
     }
 }
 
+// 可通过 `opts.styles` 追加样式的高亮组
+static STYLE_GROUPS: &[(&str, &[&str])] = &[
+    (
+        "comments",
+        &["Comment", "@comment", "@comment.documentation"],
+    ),
+    (
+        "keywords",
+        &[
+            "Keyword",
+            "Statement",
+            "Conditional",
+            "Repeat",
+            "@keyword",
+            "@keyword.function",
+            "@keyword.return",
+            "@keyword.operator",
+            "@conditional",
+            "@repeat",
+        ],
+    ),
+    (
+        "functions",
+        &[
+            "Function",
+            "@function",
+            "@function.call",
+            "@function.builtin",
+            "@method",
+            "@method.call",
+        ],
+    ),
+    (
+        "variables",
+        &[
+            "Identifier",
+            "@variable",
+            "@variable.builtin",
+            "@parameter",
+            "@property",
+        ],
+    ),
+    ("strings", &["String", "@string"]),
+    ("types", &["Type", "@type", "@type.builtin"]),
+];
+
+/// 默认选项，`setup(opts)` 与之深度合并
+pub(super) fn options_block(transparent: bool) -> String {
+    format!(
+        "\
+-- Default options, merged with `setup(opts)`
+M.options = {{
+  -- Remove backgrounds from Normal, floats, side bars and gutters
+  transparent = {},
+  -- Extra styles per syntax category, e.g. {{ comments = {{ italic = true }} }}
+  styles = {{
+    comments = {{}},
+    keywords = {{}},
+    functions = {{}},
+    variables = {{}},
+    strings = {{}},
+    types = {{}},
+  }},
+  -- Integration toggles, e.g. {{ telescope = false }}
+  integrations = {{}},
+  -- Modify the palette before highlights are built
+  on_colors = function(colors) end,
+  -- Modify highlight groups before they are applied
+  on_highlights = function(highlights, colors) end,
+}}
+",
+        transparent
+    )
+}
+
+/// `opts.styles` 各类别与 `opts.transparent` 对应的高亮组
+pub(super) fn option_groups_block() -> String {
+    let mut content = String::new();
+    content.push_str("-- Highlight groups affected by `opts.styles`\n");
+    content.push_str("local style_groups = {\n");
+    for (style, groups) in STYLE_GROUPS {
+        let groups: Vec<String> = groups
+            .iter()
+            .map(|group| format!("\"{}\"", group))
+            .collect();
+        content.push_str(&format!("  {} = {{ {} }},\n", style, groups.join(", ")));
+    }
+    content.push_str("}\n\n");
+
+    content.push_str("-- Highlight groups cleared by `opts.transparent`\n");
+    content.push_str("local transparent_groups = {\n");
+    for group in mapping::TRANSPARENT_GROUPS {
+        content.push_str(&format!("  \"{}\",\n", group));
+    }
+    content.push_str("}\n\n");
+    content
+}
+
+/// `setup`、`load`、`set_integration` 与 `highlight`，
+/// 依赖前面定义的 `M.palette`、`base_groups(p)` 与 `integration_groups(name, p)`
+pub(super) fn runtime_functions(colors_name: &str, is_dark: bool) -> String {
+    let mut content = String::new();
+
+    content.push_str("-- Configure the theme and apply it\n");
+    content.push_str("function M.setup(opts)\n");
+    content.push_str("  M.options = vim.tbl_deep_extend(\"force\", M.options, opts or {})\n");
+    content.push_str("  for name, enabled in pairs(M.options.integrations) do\n");
+    content.push_str("    if M.enabled[name] == nil then\n");
+    content.push_str("      vim.notify(\"Unknown integration: \" .. name, vim.log.levels.WARN)\n");
+    content.push_str("    else\n");
+    content.push_str("      M.enabled[name] = enabled\n");
+    content.push_str("    end\n");
+    content.push_str("  end\n");
+    content.push_str("  M.load()\n");
+    content.push_str("end\n\n");
+
+    content.push_str("-- Apply the theme with the current options\n");
+    content.push_str("function M.load()\n");
+    content.push_str("  local p = vim.deepcopy(M.palette)\n");
+    content.push_str("  M.options.on_colors(p)\n\n");
+
+    content.push_str("  local highlights = base_groups(p)\n");
+    content.push_str("  for name, enabled in pairs(M.enabled) do\n");
+    content.push_str("    if enabled then\n");
+    content.push_str("      for group, styles in pairs(integration_groups(name, p)) do\n");
+    content.push_str("        highlights[group] = styles\n");
+    content.push_str("      end\n");
+    content.push_str("    end\n");
+    content.push_str("  end\n\n");
+
+    content.push_str("  for style, groups in pairs(style_groups) do\n");
+    content.push_str("    local extra = M.options.styles[style]\n");
+    content.push_str("    for _, group in ipairs(groups) do\n");
+    content.push_str("      local hl = highlights[group]\n");
+    content.push_str("      if extra and hl and not hl.link then\n");
+    content.push_str("        highlights[group] = vim.tbl_extend(\"force\", hl, extra)\n");
+    content.push_str("      end\n");
+    content.push_str("    end\n");
+    content.push_str("  end\n\n");
+
+    content.push_str("  if M.options.transparent then\n");
+    content.push_str("    for _, group in ipairs(transparent_groups) do\n");
+    content.push_str("      local hl = highlights[group]\n");
+    content.push_str("      if hl and not hl.link then\n");
+    content
+        .push_str("        highlights[group] = vim.tbl_extend(\"force\", hl, { bg = \"NONE\" })\n");
+    content.push_str("      end\n");
+    content.push_str("    end\n");
+    content.push_str("  end\n\n");
+
+    content.push_str("  M.options.on_highlights(highlights, p)\n\n");
+
+    content.push_str("  vim.cmd('hi clear')\n");
+    content.push_str("  if vim.fn.exists('syntax_on') then\n");
+    content.push_str("    vim.cmd('syntax reset')\n");
+    content.push_str("  end\n");
+    content.push_str(&format!("  vim.g.colors_name = \"{}\"\n", colors_name));
+    content.push_str("  vim.o.termguicolors = true\n");
+    content.push_str(&format!(
+        "  vim.o.background = \"{}\"\n\n",
+        if is_dark { "dark" } else { "light" }
+    ));
+
+    content.push_str("  for group, styles in pairs(highlights) do\n");
+    content.push_str("    M.highlight(group, styles)\n");
+    content.push_str("  end\n");
+    content.push_str("  M.colors = p\n");
+    content.push_str("end\n\n");
+
+    content.push_str("-- Toggle a plugin integration at runtime\n");
+    content.push_str("function M.set_integration(name, enabled)\n");
+    content.push_str("  if M.enabled[name] == nil then\n");
+    content.push_str("    vim.notify(\"Unknown integration: \" .. name, vim.log.levels.WARN)\n");
+    content.push_str("    return\n");
+    content.push_str("  end\n");
+    content.push_str("  M.enabled[name] = enabled\n");
+    content.push_str(&format!(
+        "  if vim.g.colors_name == \"{}\" then\n",
+        colors_name
+    ));
+    content.push_str("    M.load()\n");
+    content.push_str("  end\n");
+    content.push_str("end\n\n");
+
+    content.push_str(HIGHLIGHT_HELPER);
+    content.push('\n');
+    content
+}
+
+/// 设置高亮的辅助函数
+const HIGHLIGHT_HELPER: &str = "\
+-- Helper function to set highlights
+function M.highlight(group, styles)
+  vim.api.nvim_set_hl(0, group, {
+    fg = styles.fg,
+    bg = styles.bg,
+    sp = styles.sp,
+    bold = styles.bold,
+    italic = styles.italic,
+    underline = styles.underline,
+    undercurl = styles.undercurl,
+    strikethrough = styles.strikethrough,
+    link = styles.link,
+  })
+end
+";
+
+/// Lua 标识符：字母或下划线开头，仅含字母、数字与下划线
+fn is_lua_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 fn sanitize_name(name: &str) -> String {
     name.to_lowercase()
         .replace([' ', '-', '.', ':', '/', '\\'], "_")
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// 各模式的强调色来源，按顺序取第一个存在的语法高亮组前景色
+static MODE_ACCENTS: &[(&str, &[&str])] = &[
+    ("normal", &["Function", "Identifier"]),
+    ("insert", &["String", "Character"]),
+    ("visual", &["Statement", "Keyword"]),
+    ("replace", &["Error", "Exception"]),
+    ("command", &["Number", "Constant"]),
+];
+
+pub struct LualineGenerator<'a> {
+    theme: &'a ThemeConfig,
+    highlights: &'a HashMap<String, HighlightGroup>,
+}
+
+impl<'a> LualineGenerator<'a> {
+    pub fn new(theme: &'a ThemeConfig, highlights: &'a HashMap<String, HighlightGroup>) -> Self {
+        Self { theme, highlights }
+    }
+
+    /// 生成 `lua/lualine/themes/<name>.lua`
+    pub fn generate(&self) -> Result<String> {
+        let bar_bg = self
+            .color("statusBar.background")
+            .or_else(|| self.color("editor.background"))
+            .unwrap_or_else(|| "#000000".to_string());
+        let bar_fg = self
+            .color("statusBar.foreground")
+            .or_else(|| self.color("editor.foreground"))
+            .unwrap_or_else(|| "#ffffff".to_string());
+        let item_bg = self
+            .color("statusBarItem.hoverBackground")
+            .unwrap_or_else(|| bar_bg.clone());
+        let inactive_bg = self
+            .color("statusBar.noFolderBackground")
+            .unwrap_or_else(|| bar_bg.clone());
+        let inactive_fg = self
+            .color("statusBar.noFolderForeground")
+            .or_else(|| self.color("tab.inactiveForeground"))
+            .unwrap_or_else(|| bar_fg.clone());
+
+        let mut content = String::new();
+        content.push_str(&format!("-- Name: {}\n", self.theme.name));
+        content.push_str("-- Lualine theme converted from VSCode Theme\n\n");
+        content.push_str("return {\n");
+
+        for &(mode, groups) in MODE_ACCENTS {
+            let accent = self.accent(mode, groups).unwrap_or_else(|| bar_fg.clone());
+            // replace 模式使用调试状态栏时搭配其前景色
+            let debugging = mode == "replace"
+                && self
+                    .theme
+                    .colors
+                    .contains_key("statusBar.debuggingBackground");
+            let accent_fg = debugging
+                .then(|| self.color("statusBar.debuggingForeground"))
+                .flatten()
+                .unwrap_or_else(|| bar_bg.clone());
+            content.push_str(&format!("  {} = {{\n", mode));
+            content.push_str(&format!(
+                "    a = {{ fg = \"{}\", bg = \"{}\", gui = \"bold\" }},\n",
+                accent_fg, accent
+            ));
+            content.push_str(&format!(
+                "    b = {{ fg = \"{}\", bg = \"{}\" }},\n",
+                accent, item_bg
+            ));
+            content.push_str(&format!(
+                "    c = {{ fg = \"{}\", bg = \"{}\" }},\n",
+                bar_fg, bar_bg
+            ));
+            content.push_str("  },\n");
+        }
+
+        content.push_str("  inactive = {\n");
+        for section in ["a", "b", "c"] {
+            content.push_str(&format!(
+                "    {} = {{ fg = \"{}\", bg = \"{}\" }},\n",
+                section, inactive_fg, inactive_bg
+            ));
+        }
+        content.push_str("  },\n");
+        content.push_str("}\n");
+
+        Ok(content)
+    }
+
+    /// 模式强调色，replace 模式优先使用调试状态栏颜色，缺少时退回到语法色
+    fn accent(&self, mode: &str, groups: &[&str]) -> Option<String> {
+        let syntax = || {
+            groups.iter().find_map(|group| {
+                self.highlights
+                    .get(*group)
+                    .and_then(|highlight| highlight.fg.as_deref())
+                    .and_then(|fg| self.normalize(fg))
+            })
+        };
+
+        match mode {
+            "replace" => self.color("statusBar.debuggingBackground").or_else(syntax),
+            _ => syntax().or_else(|| self.color("statusBarItem.remoteBackground")),
+        }
+    }
+
+    fn color(&self, key: &str) -> Option<String> {
+        self.theme
+            .colors
+            .get(key)
+            .and_then(|value| self.normalize(value))
+    }
+
+    /// 与状态栏背景混合，去掉透明度
+    fn normalize(&self, value: &str) -> Option<String> {
+        let bg = self
+            .theme
+            .colors
+            .get("statusBar.background")
+            .or_else(|| self.theme.colors.get("editor.background"))
+            .map(String::as_str)
+            .unwrap_or("#000000");
+        color::normalize_color(value, bg).ok()
+    }
+}
@@ -52,6 +52,17 @@ pub static EDITOR_MAPPINGS: &[(&str, (&str, &str))] = &[
     ("editorError.foreground", ("Error", "fg")),
     ("editorWarning.foreground", ("WarningMsg", "fg")),
     ("editorInfo.foreground", ("MoreMsg", "fg")),
+    // LSP document highlight (光标下单词的其他出现位置)
+    ("editor.wordHighlightBackground", ("LspReferenceText", "bg")),
+    (
+        "editor.wordHighlightTextBackground",
+        ("LspReferenceText", "bg"),
+    ),
+    ("editor.wordHighlightBackground", ("LspReferenceRead", "bg")),
+    (
+        "editor.wordHighlightStrongBackground",
+        ("LspReferenceWrite", "bg"),
+    ),
 ];
 
 // Treesitter 映射
@@ -89,6 +100,34 @@ pub static TREESITTER_MAPPINGS: &[(&str, &[&str])] = &[
     ("Error", &["@error"]),
 ];
 
+// 透明背景模式下去掉背景色的高亮组，选区、搜索与 diff 等保留背景
+pub static TRANSPARENT_GROUPS: &[&str] = &[
+    "Normal",
+    "NormalNC",
+    "NormalSB",
+    "NormalFloat",
+    "FloatBorder",
+    "SignColumn",
+    "StatusLine",
+    "StatusLineNC",
+    "TabLineFill",
+    "WinBar",
+    "WinBarNC",
+    "EndOfBuffer",
+    "LineNr",
+    "CursorLineNr",
+    "FoldColumn",
+    "Folded",
+    "NvimTreeNormal",
+    "NvimTreeNormalNC",
+    "NeoTreeNormal",
+    "NeoTreeNormalNC",
+    "AerialNormal",
+    "TroubleNormal",
+    "TroubleNormalNC",
+    "MiniFilesNormal",
+];
+
 lazy_static! {
     static ref TEXTMATE_SCOPE_MAPPINGS: HashMap<&'static str, Vec<&'static str>> = {
         let mut m = HashMap::new();
@@ -195,3 +234,11 @@ pub fn get_vim_groups_for_scope(scope: &str) -> Option<Vec<&'static str>> {
 pub fn get_vim_groups_for_semantic_token(token_type: &str) -> Option<Vec<&'static str>> {
     SEMANTIC_TOKEN_MAPPINGS.get(token_type).cloned()
 }
+
+/// 是否为编辑器 UI 高亮组 (由 `EDITOR_MAPPINGS` 或 Normal 生成)
+pub fn is_editor_group(group: &str) -> bool {
+    group == "Normal"
+        || EDITOR_MAPPINGS
+            .iter()
+            .any(|(_, (vim_group, _))| *vim_group == group)
+}
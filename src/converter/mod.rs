@@ -1,29 +1,76 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::types::token::TokenScope;
 use crate::types::{HighlightGroup, ThemeConfig};
+use crate::utils;
 use clap::ValueEnum;
 
-mod color;
+pub mod color;
+mod emacs_gen;
+mod helix_gen;
 mod lua_gen;
+mod lualine_gen;
 mod mapping;
+mod palette;
+mod plugin_gen;
+pub mod plugins;
+mod sublime_gen;
+mod terminal_gen;
+mod tools_gen;
 mod vim_gen;
+mod zed_gen;
 
+pub use emacs_gen::EmacsGenerator;
+pub use helix_gen::HelixGenerator;
 pub use lua_gen::LuaGenerator;
+pub use lualine_gen::LualineGenerator;
+pub use plugin_gen::PluginGenerator;
+pub use sublime_gen::SublimeGenerator;
+pub use terminal_gen::TerminalGenerator;
+pub use tools_gen::ToolsGenerator;
 pub use vim_gen::VimGenerator;
+pub use zed_gen::ZedGenerator;
 
 const DEFAULT_BG: &str = "#ffffff";
 const DEFAULT_FG: &str = "#000000";
 const DEFAULT_DARK_BG: &str = "#1e1e1e";
 const DEFAULT_DARK_FG: &str = "#d4d4d4";
+// WCAG AA 正文对比度
+const MIN_LABEL_CONTRAST: f32 = 4.5;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum OutputFormat {
     Vim,
     Lua,
     Both,
+    /// Complete Neovim colorscheme plugin directory
+    Plugin,
+    /// Helix theme, themes/<name>.toml
+    Helix,
+    /// Alacritty colors, alacritty/<name>.toml
+    Alacritty,
+    /// Kitty colors, kitty/<name>.conf
+    Kitty,
+    /// WezTerm color scheme, wezterm/<name>.toml
+    Wezterm,
+    /// Ghostty theme, ghostty/<name>
+    Ghostty,
+    /// foot colors, foot/<name>.ini
+    Foot,
+    /// Zed theme family, themes/<name>.json
+    Zed,
+    /// Emacs theme, <name>-theme.el
+    Emacs,
+    /// Sublime Text color scheme and tmTheme, <name>.sublime-color-scheme and <name>.tmTheme
+    Sublime,
+    /// tmux status line and pane borders, tmux/<name>.conf
+    Tmux,
+    /// fzf color options, fzf/<name>.sh
+    Fzf,
+    /// lazygit theme, lazygit/<name>.yml
+    Lazygit,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +79,15 @@ pub struct ConversionOptions {
     pub custom_name: Option<String>,
     pub include_treesitter: bool,
     pub include_lsp: bool,
+    /// 启用的插件集成，`None` 表示全部启用
+    pub plugins: Option<Vec<String>>,
+    /// 插件目录中额外生成 `colors/<name>.vim`，供 Vim 使用
+    pub vim_fallback: bool,
+    /// 透明背景，去掉 Normal、侧边栏、边栏等高亮组的背景色
+    ///
+    /// 只影响 Vim 与 Lua 输出：Lua 中作为 `transparent` 的默认值由运行时清除，
+    /// Vim 中直接写成 `guibg=NONE`，其他导出格式保留背景色
+    pub transparent: bool,
 }
 
 pub struct ThemeConverter {
@@ -41,10 +97,11 @@ pub struct ThemeConverter {
     options: ConversionOptions,
     // 高亮组映射 (组名 -> 高亮定义)
     highlights: HashMap<String, HighlightGroup>,
+    // 插件集成 (集成名 -> 该集成生成的高亮组)
+    integration_groups: Vec<(String, Vec<String>)>,
     // mappings: ThemeMappings,
-    // 生成的配置内容
-    vim_content: Option<String>,
-    lua_content: Option<String>,
+    // 生成的文件 (相对输出目录的路径, 内容)
+    outputs: Vec<(PathBuf, String)>,
 }
 
 impl ThemeConverter {
@@ -53,8 +110,8 @@ impl ThemeConverter {
             theme,
             options,
             highlights: HashMap::new(),
-            vim_content: None,
-            lua_content: None,
+            integration_groups: Vec::new(),
+            outputs: Vec::new(),
         }
     }
 
@@ -63,6 +120,7 @@ impl ThemeConverter {
         self.apply_editor_mappings()?;
         self.apply_syntax_mappings()?;
         self.apply_treesitter_mappings()?;
+        self.apply_plugin_mappings()?;
         self.generate_content()?;
 
         Ok(())
@@ -120,7 +178,7 @@ impl ThemeConverter {
         for token in self.theme.token_colors.iter() {
             match &token.scope {
                 TokenScope::Single(scope) => {
-                    println!("scope is {:?}", scope);
+                    log::debug!("scope is {:?}", scope);
                     if let Some(vim_groups) = mapping::get_vim_groups_for_scope(scope) {
                         for vim_group in vim_groups {
                             let mut highlight = HighlightGroup::default();
@@ -132,13 +190,17 @@ impl ThemeConverter {
                             if let Some(bg) = &token.settings.background {
                                 highlight.bg = Some(bg.clone());
                             }
+
+                            if let Some(font_style) = &token.settings.font_style {
+                                highlight.apply_font_style(font_style);
+                            }
                             self.highlights.insert(vim_group.to_string(), highlight);
                         }
                     }
                 }
                 TokenScope::Multiple(scopes) => {
                     for scope in scopes {
-                        println!("nulti scope is {:?}", scope);
+                        log::debug!("multi scope is {:?}", scope);
                         if let Some(vim_groups) = mapping::get_vim_groups_for_scope(scope) {
                             for vim_group in vim_groups {
                                 // self.add_token_highlight(vim_group, &token.settings);
@@ -151,6 +213,10 @@ impl ThemeConverter {
                                 if let Some(bg) = &token.settings.background {
                                     highlight.bg = Some(bg.clone());
                                 }
+
+                                if let Some(font_style) = &token.settings.font_style {
+                                    highlight.apply_font_style(font_style);
+                                }
                                 self.highlights.insert(vim_group.to_string(), highlight);
                             }
                         }
@@ -168,6 +234,10 @@ impl ThemeConverter {
                         if let Some(fg) = &style.foreground {
                             highlight.fg = Some(fg.clone());
                         }
+
+                        if let Some(font_style) = &style.font_style {
+                            highlight.apply_font_style(font_style);
+                        }
                         self.highlights.insert(vim_group.to_string(), highlight);
                     }
                 }
@@ -203,47 +273,273 @@ impl ThemeConverter {
         Ok(())
     }
 
-    fn generate_content(&mut self) -> Result<()> {
-        if matches!(
-            self.options.output_format,
-            OutputFormat::Vim | OutputFormat::Both
-        ) {
-            let vim_generator = VimGenerator::new(&self.theme, &self.highlights);
-            self.vim_content = Some(vim_generator.generate()?);
+    /// 应用第三方插件集成
+    fn apply_plugin_mappings(&mut self) -> Result<()> {
+        let symbol_palette = plugins::symbol_palette(&self.theme.colors, &self.highlights);
+
+        for integration in self.selected_integrations()? {
+            let mut groups: Vec<String> = Vec::new();
+            for &(vs_key, (vim_group, attr)) in integration.mappings {
+                if let Some(color) = self.theme.colors.get(vs_key) {
+                    self.add_highlight_attr(vim_group, attr, &color.clone());
+                    if !groups.iter().any(|group| group == vim_group) {
+                        groups.push(vim_group.to_string());
+                    }
+                } else {
+                    log::debug!("Missing {} color mapping for: {}", integration.name, vs_key);
+                }
+            }
+            for &(group, target) in integration.links {
+                if self.highlights.contains_key(target) {
+                    self.add_highlight(
+                        group,
+                        HighlightGroup {
+                            link: Some(target.to_string()),
+                            ..Default::default()
+                        },
+                    );
+                    groups.push(group.to_string());
+                }
+            }
+            for pattern in integration.kind_groups {
+                for (kind, color) in &symbol_palette {
+                    let group = pattern.replace("{}", kind);
+                    self.add_highlight_attr(&group, "fg", color);
+                    if !groups.contains(&group) {
+                        groups.push(group);
+                    }
+                }
+            }
+            for group in integration.contrast {
+                self.ensure_readable(group);
+            }
+            self.integration_groups
+                .push((integration.name.to_string(), groups));
+        }
+        Ok(())
+    }
+
+    /// 前景色与背景色对比度不足时调整前景色，透明度先与 Normal 背景混合
+    /// 无法解析的颜色只记录日志，与各生成器跳过无效颜色一致
+    fn ensure_readable(&mut self, group: &str) {
+        let Some(normal_bg) = self.highlights.get("Normal").and_then(|hl| hl.bg.clone()) else {
+            return;
+        };
+        let Some(highlight) = self.highlights.get_mut(group) else {
+            return;
+        };
+        let Some(fg) = &highlight.fg else {
+            return;
+        };
+
+        let adjusted = color::normalize_color(fg, &normal_bg).and_then(|fg| {
+            let bg =
+                color::normalize_color(highlight.bg.as_deref().unwrap_or(&normal_bg), &normal_bg)?;
+            let readable = color::ensure_contrast(&fg, &bg, MIN_LABEL_CONTRAST)?;
+            Ok((fg, readable))
+        });
+        let (fg, readable) = match adjusted {
+            Ok(colors) => colors,
+            Err(err) => {
+                log::debug!("Skip contrast check for {}: {}", group, err);
+                return;
+            }
+        };
+        if readable != fg {
+            log::debug!(
+                "Adjusted {} foreground {} -> {} for contrast",
+                group,
+                fg,
+                readable
+            );
+            highlight.fg = Some(readable);
         }
+    }
 
-        if matches!(
-            self.options.output_format,
-            OutputFormat::Lua | OutputFormat::Both
-        ) {
-            let lua_generator = LuaGenerator::new(&self.theme, &self.highlights);
-            self.lua_content = Some(lua_generator.generate()?);
+    fn selected_integrations(&self) -> Result<Vec<&'static plugins::Integration>> {
+        let Some(names) = &self.options.plugins else {
+            return Ok(plugins::INTEGRATIONS.to_vec());
+        };
+
+        let mut selected = Vec::new();
+        for name in names {
+            match plugins::find_integration(name) {
+                Some(integration) => selected.push(integration),
+                None => bail!(
+                    "Unknown plugin integration: {} (available: {})",
+                    name,
+                    plugins::integration_names().join(", ")
+                ),
+            }
+        }
+        Ok(selected)
+    }
+
+    fn generate_content(&mut self) -> Result<()> {
+        let sanitized_name = sanitize_name(&self.get_theme_name());
+
+        match self.options.output_format {
+            OutputFormat::Vim => self.generate_vim(&sanitized_name)?,
+            OutputFormat::Lua => self.generate_lua(&sanitized_name)?,
+            OutputFormat::Both => {
+                self.generate_vim(&sanitized_name)?;
+                self.generate_lua(&sanitized_name)?;
+            }
+            OutputFormat::Plugin => {
+                let plugin_generator = PluginGenerator::new(
+                    &self.theme,
+                    &self.highlights,
+                    &self.integration_groups,
+                    &sanitized_name,
+                    self.options.vim_fallback,
+                )
+                .with_transparent(self.options.transparent);
+                self.outputs = plugin_generator.generate()?;
+            }
+            OutputFormat::Helix => {
+                let helix_generator = HelixGenerator::new(&self.theme, &self.highlights);
+                self.outputs.push((
+                    PathBuf::from("themes").join(format!("{}.toml", sanitized_name)),
+                    helix_generator.generate()?,
+                ));
+            }
+            OutputFormat::Zed => {
+                let zed_generator =
+                    ZedGenerator::new(&self.theme, &self.highlights, &self.get_theme_name());
+                self.outputs.push((
+                    PathBuf::from("themes").join(format!("{}.json", sanitized_name)),
+                    zed_generator.generate()?,
+                ));
+            }
+            OutputFormat::Emacs => {
+                let emacs_generator =
+                    EmacsGenerator::new(&self.theme, &self.highlights, &sanitized_name);
+                self.outputs.push((
+                    PathBuf::from(emacs_generator.file_name()),
+                    emacs_generator.generate()?,
+                ));
+            }
+            OutputFormat::Sublime => {
+                let sublime_generator = SublimeGenerator::new(&self.theme, &self.highlights);
+                self.outputs.push((
+                    PathBuf::from(format!("{}.sublime-color-scheme", sanitized_name)),
+                    sublime_generator.color_scheme()?,
+                ));
+                self.outputs.push((
+                    PathBuf::from(format!("{}.tmTheme", sanitized_name)),
+                    sublime_generator.tm_theme(),
+                ));
+            }
+            OutputFormat::Alacritty
+            | OutputFormat::Kitty
+            | OutputFormat::Wezterm
+            | OutputFormat::Ghostty
+            | OutputFormat::Foot => self.generate_terminal(&sanitized_name),
+            OutputFormat::Tmux | OutputFormat::Fzf | OutputFormat::Lazygit => {
+                self.generate_tools(&sanitized_name)
+            }
         }
 
         Ok(())
     }
 
+    /// 终端模拟器配色，每个终端一个目录，避免与其他格式的文件重名
+    fn generate_terminal(&mut self, sanitized_name: &str) {
+        let terminal_generator =
+            TerminalGenerator::new(&self.theme, &self.highlights).with_name(sanitized_name);
+        let (path, content) = match self.options.output_format {
+            OutputFormat::Kitty => (
+                PathBuf::from("kitty").join(format!("{}.conf", sanitized_name)),
+                terminal_generator.kitty(),
+            ),
+            OutputFormat::Wezterm => (
+                PathBuf::from("wezterm").join(format!("{}.toml", sanitized_name)),
+                terminal_generator.wezterm(),
+            ),
+            OutputFormat::Ghostty => (
+                PathBuf::from("ghostty").join(sanitized_name),
+                terminal_generator.ghostty(),
+            ),
+            OutputFormat::Foot => (
+                PathBuf::from("foot").join(format!("{}.ini", sanitized_name)),
+                terminal_generator.foot(),
+            ),
+            OutputFormat::Alacritty => (
+                PathBuf::from("alacritty").join(format!("{}.toml", sanitized_name)),
+                terminal_generator.alacritty(),
+            ),
+            format => unreachable!("{:?} is not a terminal output format", format),
+        };
+        self.outputs.push((path, content));
+    }
+
+    /// tmux、fzf 与 lazygit 配置片段，路径相对于各工具的配置目录
+    fn generate_tools(&mut self, sanitized_name: &str) {
+        let tools_generator = ToolsGenerator::new(&self.theme, &self.highlights);
+        let (path, content) = match self.options.output_format {
+            OutputFormat::Tmux => (
+                PathBuf::from("tmux").join(format!("{}.conf", sanitized_name)),
+                tools_generator.tmux(),
+            ),
+            OutputFormat::Fzf => (
+                PathBuf::from("fzf").join(format!("{}.sh", sanitized_name)),
+                tools_generator.fzf(),
+            ),
+            OutputFormat::Lazygit => (
+                PathBuf::from("lazygit").join(format!("{}.yml", sanitized_name)),
+                tools_generator.lazygit(),
+            ),
+            format => unreachable!("{:?} is not a tool output format", format),
+        };
+        self.outputs.push((path, content));
+    }
+
+    fn generate_vim(&mut self, sanitized_name: &str) -> Result<()> {
+        let vim_generator =
+            VimGenerator::new(&self.theme, &self.highlights, &self.integration_groups)
+                .with_transparent(self.options.transparent);
+        self.outputs.push((
+            PathBuf::from(format!("{}.vim", sanitized_name)),
+            vim_generator.generate()?,
+        ));
+        Ok(())
+    }
+
+    fn generate_lua(&mut self, sanitized_name: &str) -> Result<()> {
+        let lua_generator =
+            LuaGenerator::new(&self.theme, &self.highlights, &self.integration_groups)
+                .with_transparent(self.options.transparent);
+        self.outputs.push((
+            PathBuf::from(format!("{}.lua", sanitized_name)),
+            lua_generator.generate()?,
+        ));
+        self.outputs.push((
+            PathBuf::from("lua")
+                .join(sanitized_name)
+                .join("palette.lua"),
+            lua_generator.generate_palette(),
+        ));
+
+        let lualine_generator = LualineGenerator::new(&self.theme, &self.highlights);
+        self.outputs.push((
+            PathBuf::from("lua")
+                .join("lualine")
+                .join("themes")
+                .join(format!("{}.lua", sanitized_name)),
+            lualine_generator.generate()?,
+        ));
+        Ok(())
+    }
+
     pub fn save_to_files(&self, output_dir: &Path) -> Result<Vec<PathBuf>> {
         let mut output_files = Vec::new();
         std::fs::create_dir_all(output_dir).context("Failed to create output directory")?;
 
-        let theme_name = self.get_theme_name();
-        let sanitized_name = sanitize_name(&theme_name);
-
-        if let Some(vim_content) = &self.vim_content {
-            let vim_path = output_dir.join(format!("{}.vim", sanitized_name));
-            std::fs::write(&vim_path, vim_content)
-                .with_context(|| format!("Failed to write Vim file: {}", vim_path.display()))?;
-            println!("save to vim_path {:?}", vim_path.clone());
-            output_files.push(vim_path);
-        }
-
-        if let Some(lua_content) = &self.lua_content {
-            let lua_path = output_dir.join(format!("{}.lua", sanitized_name));
-            std::fs::write(&lua_path, lua_content)
-                .with_context(|| format!("Failed to write Lua file: {}", lua_path.display()))?;
-            println!("save to path{:?}", lua_path.clone());
-            output_files.push(lua_path);
+        for (relative_path, content) in &self.outputs {
+            let path = output_dir.join(relative_path);
+            utils::write_file(&path, content)?;
+            log::debug!("save to path {:?}", path);
+            output_files.push(path);
         }
 
         Ok(output_files)
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// 色相区间 (上界, 名称)
+static HUE_NAMES: &[(f32, &str)] = &[
+    (15.0, "red"),
+    (45.0, "orange"),
+    (70.0, "yellow"),
+    (165.0, "green"),
+    (195.0, "cyan"),
+    (255.0, "blue"),
+    (290.0, "purple"),
+    (345.0, "magenta"),
+    (360.0, "red"),
+];
+
+// 彩度 (最大与最小通道之差) 低于该值视为灰阶
+const NEUTRAL_CHROMA: f32 = 0.1;
+// 彩度低于该值加 `_muted` 后缀
+const MUTED_CHROMA: f32 = 0.25;
+
+/// 主题调色板，为每个不同的颜色分配名称
+///
+/// - `bg`、`fg`: Normal 的背景色与前景色
+/// - `black`、`white`: 纯黑与纯白
+/// - `surface0..n`: 与背景同为深色 (或浅色) 的灰阶，按与背景的亮度差从小到大编号
+/// - `subtext0..n`: 其余灰阶，按与前景的亮度差从小到大编号
+/// - `red`、`blue_muted`、`green_dark` 等: 按色相命名，同名时按使用次数依次加数字后缀
+pub struct Palette {
+    // (名称, 颜色)，按输出顺序
+    entries: Vec<(String, String)>,
+    // 颜色 -> 名称
+    names: HashMap<String, String>,
+}
+
+impl Palette {
+    /// 收集主题中的颜色与高亮组实际使用的颜色，透明度先与编辑器背景混合
+    pub fn new(theme: &ThemeConfig, highlights: &HashMap<String, HighlightGroup>) -> Self {
+        let normal = highlights.get("Normal");
+        let editor_bg = theme
+            .colors
+            .get("editor.background")
+            .or_else(|| normal.and_then(|hl| hl.bg.as_ref()))
+            .cloned()
+            .unwrap_or_else(|| "#000000".to_string());
+        let normalize = |value: &str| color::normalize_color(value, &editor_bg).ok();
+
+        let bg = normalize(&editor_bg).unwrap_or_else(|| "#000000".to_string());
+        let fg = normal
+            .and_then(|hl| hl.fg.as_deref())
+            .and_then(normalize)
+            .unwrap_or_else(|| "#ffffff".to_string());
+
+        // 颜色 -> 在高亮组中的使用次数
+        let mut usage: HashMap<String, usize> = HashMap::new();
+        for highlight in highlights.values() {
+            for value in [&highlight.fg, &highlight.bg, &highlight.sp]
+                .into_iter()
+                .flatten()
+            {
+                if let Some(normalized) = normalize(value) {
+                    *usage.entry(normalized).or_default() += 1;
+                }
+            }
+        }
+        for value in theme.get_unique_colors() {
+            if let Some(normalized) = normalize(&value) {
+                usage.entry(normalized).or_default();
+            }
+        }
+
+        let mut colors: Vec<(String, usize)> = usage.into_iter().collect();
+        colors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let bg_lightness = lightness(&bg);
+        let fg_lightness = lightness(&fg);
+        let bg_is_dark = color::is_dark_color(&bg);
+
+        let mut surfaces = Vec::new();
+        let mut subtexts = Vec::new();
+        let mut extremes = Vec::new();
+        let mut hues: Vec<(usize, String, usize, String)> = Vec::new();
+        let mut hue_counts: HashMap<String, usize> = HashMap::new();
+
+        for (value, _) in colors {
+            if value == bg || value == fg {
+                continue;
+            }
+            if value == "#000000" || value == "#ffffff" {
+                let name = if value == "#000000" { "black" } else { "white" };
+                extremes.push((name.to_string(), value));
+                continue;
+            }
+            let Ok((hue, saturation, lightness)) = color::rgb_to_hsl(&value) else {
+                continue;
+            };
+            let chroma = saturation * (1.0 - (2.0 * lightness - 1.0).abs());
+
+            if chroma < NEUTRAL_CHROMA {
+                if color::is_dark_color(&value) == bg_is_dark {
+                    surfaces.push(((lightness - bg_lightness).abs(), value));
+                } else {
+                    subtexts.push(((lightness - fg_lightness).abs(), value));
+                }
+                continue;
+            }
+
+            let (bucket, base) = hue_name(hue, chroma, lightness);
+            let count = hue_counts.entry(base.clone()).or_default();
+            *count += 1;
+            hues.push((bucket, base, *count, value));
+        }
+
+        let mut entries = vec![("bg".to_string(), bg), ("fg".to_string(), fg)];
+        extremes.sort();
+        entries.extend(extremes);
+        for (prefix, mut neutrals) in [("surface", surfaces), ("subtext", subtexts)] {
+            neutrals.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+            for (index, (_, value)) in neutrals.into_iter().enumerate() {
+                entries.push((format!("{}{}", prefix, index), value));
+            }
+        }
+        hues.sort();
+        entries.extend(hues.into_iter().map(|(_, base, count, value)| {
+            let name = if count == 1 {
+                base
+            } else {
+                format!("{}{}", base, count)
+            };
+            (name, value)
+        }));
+
+        let names = entries
+            .iter()
+            .map(|(name, value)| (value.clone(), name.clone()))
+            .collect();
+
+        Self { entries, names }
+    }
+
+    /// (名称, 颜色) 列表
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.entries
+    }
+
+    /// 已混合透明度的颜色对应的名称
+    pub fn name_of(&self, value: &str) -> Option<&str> {
+        self.names.get(value).map(String::as_str)
+    }
+}
+
+fn lightness(value: &str) -> f32 {
+    color::rgb_to_hsl(value)
+        .map(|(_, _, lightness)| lightness)
+        .unwrap_or(0.0)
+}
+
+/// 按色相取名，低彩度加 `_muted`，过暗或过亮加 `_dark` / `_light`
+///
+/// 同时返回色相区间序号，用于排序
+fn hue_name(hue: f32, chroma: f32, lightness: f32) -> (usize, String) {
+    let (bucket, base) = HUE_NAMES
+        .iter()
+        .enumerate()
+        .find(|(_, (upper, _))| hue < *upper)
+        .map(|(index, (_, name))| (index % (HUE_NAMES.len() - 1), *name))
+        .unwrap_or((0, "red"));
+
+    let name = if chroma < MUTED_CHROMA {
+        format!("{}_muted", base)
+    } else if lightness < 0.3 {
+        format!("{}_dark", base)
+    } else if lightness > 0.8 {
+        format!("{}_light", base)
+    } else {
+        base.to_string()
+    };
+    (bucket, name)
+}
@@ -0,0 +1,292 @@
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use super::lua_gen::{option_groups_block, options_block, runtime_functions};
+use super::{LuaGenerator, LualineGenerator, VimGenerator, mapping};
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// 基础高亮组拆分到的模块，按顺序加载
+static GROUP_MODULES: &[&str] = &["editor", "syntax", "treesitter"];
+
+/// 生成可直接安装的 Neovim 配色插件目录
+///
+/// ```text
+/// colors/<name>.lua
+/// lua/<name>/init.lua
+/// lua/<name>/palette.lua
+/// lua/<name>/groups/{editor,syntax,treesitter}.lua
+/// lua/<name>/groups/integrations/<integration>.lua
+/// lua/lualine/themes/<name>.lua
+/// README.md
+/// LICENSE
+/// ```
+pub struct PluginGenerator<'a> {
+    theme: &'a ThemeConfig,
+    highlights: &'a HashMap<String, HighlightGroup>,
+    integrations: &'a [(String, Vec<String>)],
+    // 模块名，同时用作 colors_name
+    name: &'a str,
+    // 额外生成 colors/<name>.vim
+    vim_fallback: bool,
+    // 默认开启透明背景
+    transparent: bool,
+}
+
+impl<'a> PluginGenerator<'a> {
+    pub fn new(
+        theme: &'a ThemeConfig,
+        highlights: &'a HashMap<String, HighlightGroup>,
+        integrations: &'a [(String, Vec<String>)],
+        name: &'a str,
+        vim_fallback: bool,
+    ) -> Self {
+        Self {
+            theme,
+            highlights,
+            integrations,
+            name,
+            vim_fallback,
+            transparent: false,
+        }
+    }
+
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    /// 返回 (相对路径, 文件内容) 列表
+    pub fn generate(&self) -> Result<Vec<(PathBuf, String)>> {
+        let lua = LuaGenerator::new(self.theme, self.highlights, self.integrations)
+            .with_transparent(self.transparent);
+        let module_dir = PathBuf::from("lua").join(self.name);
+        let mut files = Vec::new();
+
+        files.push((
+            PathBuf::from("colors").join(format!("{}.lua", self.name)),
+            self.generate_colors_entry(),
+        ));
+        if self.vim_fallback {
+            files.push((
+                PathBuf::from("colors").join(format!("{}.vim", self.name)),
+                self.generate_vim_entry()?,
+            ));
+        }
+
+        files.push((module_dir.join("init.lua"), self.generate_init(&lua)));
+        files.push((module_dir.join("palette.lua"), lua.generate_palette()));
+
+        let integration_groups: HashSet<&String> = self
+            .integrations
+            .iter()
+            .flat_map(|(_, groups)| groups)
+            .collect();
+        for module in GROUP_MODULES {
+            let groups: Vec<&String> = self
+                .highlights
+                .keys()
+                .filter(|group| !integration_groups.contains(group))
+                .filter(|group| group_module(group) == *module)
+                .collect();
+            files.push((
+                module_dir.join("groups").join(format!("{}.lua", module)),
+                self.generate_group_module(&lua, module, &groups)?,
+            ));
+        }
+        for (name, groups) in self.integrations {
+            let groups: Vec<&String> = groups.iter().collect();
+            files.push((
+                module_dir
+                    .join("groups")
+                    .join("integrations")
+                    .join(format!("{}.lua", name)),
+                self.generate_group_module(&lua, name, &groups)?,
+            ));
+        }
+
+        let lualine = LualineGenerator::new(self.theme, self.highlights);
+        files.push((
+            PathBuf::from("lua")
+                .join("lualine")
+                .join("themes")
+                .join(format!("{}.lua", self.name)),
+            lualine.generate()?,
+        ));
+
+        files.push((PathBuf::from("README.md"), self.generate_readme()));
+        files.push((PathBuf::from("LICENSE"), self.generate_license()));
+
+        Ok(files)
+    }
+
+    /// `:colorscheme <name>` 的入口
+    fn generate_colors_entry(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("-- Name: {}\n\n", self.theme.name));
+        content.push_str(&format!("require(\"{}\").load()\n", self.name));
+        content
+    }
+
+    /// Vim 使用 VimL 高亮，Neovim 转交给 Lua 模块
+    fn generate_vim_entry(&self) -> Result<String> {
+        let vim = VimGenerator::new(self.theme, self.highlights, self.integrations)
+            .with_colors_name(self.name)
+            .with_transparent(self.transparent);
+
+        let mut content = String::new();
+        content.push_str("if has('nvim')\n");
+        content.push_str(&format!("  lua require(\"{}\").load()\n", self.name));
+        content.push_str("  finish\n");
+        content.push_str("endif\n\n");
+        content.push_str(&vim.generate()?);
+        Ok(content)
+    }
+
+    fn generate_init(&self, lua: &LuaGenerator) -> String {
+        let mut content = String::new();
+
+        content.push_str(&format!("-- Name: {}\n\n", self.theme.name));
+        content.push_str("local M = {}\n\n");
+
+        content.push_str("-- Color palette\n");
+        content.push_str(&format!(
+            "M.palette = require(\"{}.palette\")\n\n",
+            self.name
+        ));
+
+        content.push_str(&lua.generate_enabled());
+        content.push_str(&options_block(self.transparent));
+        content.push('\n');
+        content.push_str(&option_groups_block());
+
+        content.push_str("-- Highlight groups built from the palette\n");
+        content.push_str("local function base_groups(p)\n");
+        content.push_str("  local groups = {}\n");
+        content.push_str(&format!(
+            "  for _, module in ipairs({{ {} }}) do\n",
+            GROUP_MODULES
+                .iter()
+                .map(|module| format!("\"{}\"", module))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        content.push_str(&format!(
+            "    for group, styles in pairs(require(\"{}.groups.\" .. module)(p)) do\n",
+            self.name
+        ));
+        content.push_str("      groups[group] = styles\n");
+        content.push_str("    end\n");
+        content.push_str("  end\n");
+        content.push_str("  return groups\n");
+        content.push_str("end\n\n");
+
+        content.push_str("local function integration_groups(name, p)\n");
+        content.push_str(&format!(
+            "  return require(\"{}.groups.integrations.\" .. name)(p)\n",
+            self.name
+        ));
+        content.push_str("end\n\n");
+
+        content.push_str(&runtime_functions(self.name, lua.is_dark_theme()));
+        content.push_str("return M\n");
+
+        content
+    }
+
+    /// 命名调色板，各高亮组模块以 `p.<name>` 引用
+    fn generate_group_module(
+        &self,
+        lua: &LuaGenerator,
+        module: &str,
+        groups: &[&String],
+    ) -> Result<String> {
+        let mut groups = groups.to_vec();
+        groups.sort();
+
+        // 调色板由 init.lua 传入，on_colors 修改后的颜色才能生效
+        let mut content = String::new();
+        content.push_str(&format!("-- {} highlight groups\n\n", module));
+        content.push_str("return function(p)\n");
+        content.push_str("  return {\n");
+        for group in groups {
+            if let Some(highlight) = self.highlights.get(group) {
+                content.push_str(&format!(
+                    "    {},\n",
+                    lua.format_highlight_table(group, highlight)?
+                ));
+            }
+        }
+        content.push_str("  }\n");
+        content.push_str("end\n");
+        Ok(content)
+    }
+
+    fn generate_readme(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# {}\n\n", self.name));
+        content.push_str(&format!(
+            "Neovim colorscheme converted from the VSCode theme \"{}\".\n\n",
+            self.theme.name
+        ));
+        content.push_str("## Installation\n\n");
+        content.push_str("With [lazy.nvim](https://github.com/folke/lazy.nvim):\n\n");
+        content.push_str("```lua\n");
+        content.push_str(&format!(
+            "{{ \"<user>/{}\", lazy = false, priority = 1000 }}\n",
+            self.name
+        ));
+        content.push_str("```\n\n");
+        content.push_str("## Usage\n\n");
+        content.push_str("```vim\n");
+        content.push_str(&format!("colorscheme {}\n", self.name));
+        content.push_str("```\n\n");
+        content.push_str("Options can be passed to `setup`, which also applies the theme:\n\n");
+        content.push_str("```lua\n");
+        content.push_str(&format!("require(\"{}\").setup({{\n", self.name));
+        content.push_str("  styles = { comments = { italic = true } },\n");
+        content.push_str("  integrations = { telescope = false },\n");
+        content.push_str("  on_colors = function(colors) end,\n");
+        content.push_str("  on_highlights = function(highlights, colors) end,\n");
+        content.push_str("})\n");
+        content.push_str("```\n\n");
+        content.push_str("Plugin integrations can be toggled at runtime:\n\n");
+        content.push_str("```lua\n");
+        content.push_str(&format!(
+            "require(\"{}\").set_integration(\"telescope\", false)\n",
+            self.name
+        ));
+        content.push_str("```\n\n");
+        content.push_str("A matching lualine theme is included:\n\n");
+        content.push_str("```lua\n");
+        content.push_str(&format!(
+            "require(\"lualine\").setup({{ options = {{ theme = \"{}\" }} }})\n",
+            self.name
+        ));
+        content.push_str("```\n");
+        content
+    }
+
+    fn generate_license(&self) -> String {
+        let mut content = String::new();
+        content.push_str("MIT License\n\n");
+        content.push_str("Copyright (c) <year> <copyright holders>\n\n");
+        content.push_str(&format!(
+            "This colorscheme was converted from the VSCode theme \"{}\".\n",
+            self.theme.name
+        ));
+        content.push_str("Check the license of the original theme before publishing.\n");
+        content
+    }
+}
+
+/// 基础高亮组所属模块
+fn group_module(group: &str) -> &'static str {
+    if group.starts_with('@') {
+        "treesitter"
+    } else if mapping::is_editor_group(group) {
+        "editor"
+    } else {
+        "syntax"
+    }
+}
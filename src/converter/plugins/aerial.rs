@@ -0,0 +1,16 @@
+use super::Integration;
+
+// aerial.nvim 大纲窗口，对应 VSCode 的大纲视图
+pub static INTEGRATION: Integration = Integration {
+    name: "aerial",
+    mappings: &[
+        ("sideBar.background", ("AerialNormal", "bg")),
+        ("sideBar.foreground", ("AerialNormal", "fg")),
+        ("list.inactiveSelectionBackground", ("AerialLine", "bg")),
+        ("list.activeSelectionBackground", ("AerialLine", "bg")),
+        ("tree.indentGuidesStroke", ("AerialGuide", "fg")),
+    ],
+    links: &[],
+    kind_groups: &["Aerial{}Icon"],
+    contrast: &[],
+};
@@ -0,0 +1,17 @@
+use super::Integration;
+
+// alpha-nvim 启动页，对应 VSCode 的欢迎页
+pub static INTEGRATION: Integration = Integration {
+    name: "alpha",
+    mappings: &[
+        ("textLink.foreground", ("AlphaHeader", "fg")),
+        ("welcomePage.progress.foreground", ("AlphaHeader", "fg")),
+        ("textLink.foreground", ("AlphaHeaderLabel", "fg")),
+        ("editor.foreground", ("AlphaButtons", "fg")),
+        ("textLink.activeForeground", ("AlphaShortcut", "fg")),
+        ("descriptionForeground", ("AlphaFooter", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
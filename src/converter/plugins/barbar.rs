@@ -0,0 +1,64 @@
+use super::Integration;
+
+// barbar.nvim，对应 VSCode 的编辑器标签栏
+pub static INTEGRATION: Integration = Integration {
+    name: "barbar",
+    mappings: &[
+        ("tab.border", ("BufferTabpageFill", "bg")),
+        (
+            "editorGroupHeader.tabsBackground",
+            ("BufferTabpageFill", "bg"),
+        ),
+        ("tab.inactiveForeground", ("BufferTabpageFill", "fg")),
+        ("editorGroupHeader.tabsBackground", ("BufferTabpages", "bg")),
+        ("tab.activeForeground", ("BufferTabpages", "fg")),
+        // 当前缓冲区
+        ("tab.activeBackground", ("BufferCurrent", "bg")),
+        ("tab.activeForeground", ("BufferCurrent", "fg")),
+        ("tab.activeBackground", ("BufferCurrentIndex", "bg")),
+        ("tab.activeForeground", ("BufferCurrentIndex", "fg")),
+        ("tab.activeBackground", ("BufferCurrentMod", "bg")),
+        ("tab.activeModifiedBorder", ("BufferCurrentMod", "fg")),
+        ("tab.activeBackground", ("BufferCurrentSign", "bg")),
+        ("focusBorder", ("BufferCurrentSign", "fg")),
+        ("tab.activeBorderTop", ("BufferCurrentSign", "fg")),
+        // 在其他窗口中可见的缓冲区
+        ("tab.unfocusedActiveBackground", ("BufferVisible", "bg")),
+        ("tab.unfocusedActiveForeground", ("BufferVisible", "fg")),
+        (
+            "tab.unfocusedActiveBackground",
+            ("BufferVisibleIndex", "bg"),
+        ),
+        (
+            "tab.unfocusedActiveForeground",
+            ("BufferVisibleIndex", "fg"),
+        ),
+        ("tab.unfocusedActiveBackground", ("BufferVisibleMod", "bg")),
+        (
+            "tab.unfocusedActiveModifiedBorder",
+            ("BufferVisibleMod", "fg"),
+        ),
+        ("tab.unfocusedActiveBackground", ("BufferVisibleSign", "bg")),
+        ("tab.unfocusedActiveBorderTop", ("BufferVisibleSign", "fg")),
+        // 交替缓冲区，对应鼠标悬停的标签
+        ("tab.hoverBackground", ("BufferAlternate", "bg")),
+        ("tab.inactiveForeground", ("BufferAlternate", "fg")),
+        ("tab.hoverForeground", ("BufferAlternate", "fg")),
+        ("tab.hoverBackground", ("BufferAlternateMod", "bg")),
+        ("tab.inactiveModifiedBorder", ("BufferAlternateMod", "fg")),
+        ("tab.hoverBackground", ("BufferAlternateSign", "bg")),
+        ("tab.hoverBorder", ("BufferAlternateSign", "fg")),
+        // 非当前缓冲区
+        ("tab.inactiveBackground", ("BufferInactive", "bg")),
+        ("tab.inactiveForeground", ("BufferInactive", "fg")),
+        ("tab.inactiveBackground", ("BufferInactiveIndex", "bg")),
+        ("tab.inactiveForeground", ("BufferInactiveIndex", "fg")),
+        ("tab.inactiveBackground", ("BufferInactiveMod", "bg")),
+        ("tab.inactiveModifiedBorder", ("BufferInactiveMod", "fg")),
+        ("tab.inactiveBackground", ("BufferInactiveSign", "bg")),
+        ("tab.border", ("BufferInactiveSign", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
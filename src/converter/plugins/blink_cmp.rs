@@ -0,0 +1,59 @@
+use super::Integration;
+
+// blink.cmp，对应 VSCode 的补全建议窗口与悬浮文档
+pub static INTEGRATION: Integration = Integration {
+    name: "blink-cmp",
+    mappings: &[
+        // 补全菜单
+        ("editorSuggestWidget.background", ("BlinkCmpMenu", "bg")),
+        ("editorSuggestWidget.foreground", ("BlinkCmpMenu", "fg")),
+        (
+            "editorSuggestWidget.background",
+            ("BlinkCmpMenuBorder", "bg"),
+        ),
+        ("editorSuggestWidget.border", ("BlinkCmpMenuBorder", "fg")),
+        (
+            "editorSuggestWidget.selectedBackground",
+            ("BlinkCmpMenuSelection", "bg"),
+        ),
+        (
+            "editorSuggestWidget.selectedForeground",
+            ("BlinkCmpMenuSelection", "fg"),
+        ),
+        ("editorSuggestWidget.foreground", ("BlinkCmpLabel", "fg")),
+        (
+            "editorSuggestWidget.highlightForeground",
+            ("BlinkCmpLabelMatch", "fg"),
+        ),
+        ("disabledForeground", ("BlinkCmpLabelDeprecated", "fg")),
+        ("descriptionForeground", ("BlinkCmpLabelDetail", "fg")),
+        ("descriptionForeground", ("BlinkCmpLabelDescription", "fg")),
+        ("descriptionForeground", ("BlinkCmpSource", "fg")),
+        ("editorSuggestWidget.foreground", ("BlinkCmpKind", "fg")),
+        ("editorGhostText.foreground", ("BlinkCmpGhostText", "fg")),
+        // 文档与签名窗口
+        ("editorHoverWidget.background", ("BlinkCmpDoc", "bg")),
+        ("editorHoverWidget.foreground", ("BlinkCmpDoc", "fg")),
+        ("editorHoverWidget.background", ("BlinkCmpDocBorder", "bg")),
+        ("editorHoverWidget.border", ("BlinkCmpDocBorder", "fg")),
+        (
+            "editorHoverWidget.background",
+            ("BlinkCmpSignatureHelp", "bg"),
+        ),
+        (
+            "editorHoverWidget.foreground",
+            ("BlinkCmpSignatureHelp", "fg"),
+        ),
+        (
+            "editorHoverWidget.background",
+            ("BlinkCmpSignatureHelpBorder", "bg"),
+        ),
+        (
+            "editorHoverWidget.border",
+            ("BlinkCmpSignatureHelpBorder", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &["BlinkCmpKind{}"],
+    contrast: &[],
+};
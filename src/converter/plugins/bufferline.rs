@@ -0,0 +1,103 @@
+use super::Integration;
+
+// bufferline.nvim，对应 VSCode 的编辑器标签栏
+pub static INTEGRATION: Integration = Integration {
+    name: "bufferline",
+    mappings: &[
+        // 背景条
+        ("tab.border", ("BufferLineFill", "bg")),
+        ("editorGroupHeader.tabsBackground", ("BufferLineFill", "bg")),
+        // 非当前缓冲区
+        ("tab.inactiveBackground", ("BufferLineBackground", "bg")),
+        ("tab.inactiveForeground", ("BufferLineBackground", "fg")),
+        ("tab.inactiveBackground", ("BufferLineCloseButton", "bg")),
+        ("tab.inactiveForeground", ("BufferLineCloseButton", "fg")),
+        ("tab.inactiveBackground", ("BufferLineModified", "bg")),
+        ("tab.inactiveModifiedBorder", ("BufferLineModified", "fg")),
+        ("tab.inactiveBackground", ("BufferLineSeparator", "bg")),
+        (
+            "editorGroupHeader.tabsBackground",
+            ("BufferLineSeparator", "fg"),
+        ),
+        ("tab.inactiveBackground", ("BufferLineTab", "bg")),
+        ("tab.inactiveForeground", ("BufferLineTab", "fg")),
+        // 在其他窗口中可见的缓冲区
+        ("tab.inactiveBackground", ("BufferLineBufferVisible", "bg")),
+        (
+            "tab.unfocusedActiveForeground",
+            ("BufferLineBufferVisible", "fg"),
+        ),
+        (
+            "tab.inactiveBackground",
+            ("BufferLineCloseButtonVisible", "bg"),
+        ),
+        (
+            "tab.unfocusedActiveForeground",
+            ("BufferLineCloseButtonVisible", "fg"),
+        ),
+        (
+            "tab.inactiveBackground",
+            ("BufferLineModifiedVisible", "bg"),
+        ),
+        (
+            "tab.unfocusedActiveModifiedBorder",
+            ("BufferLineModifiedVisible", "fg"),
+        ),
+        (
+            "tab.inactiveBackground",
+            ("BufferLineSeparatorVisible", "bg"),
+        ),
+        (
+            "editorGroupHeader.tabsBackground",
+            ("BufferLineSeparatorVisible", "fg"),
+        ),
+        (
+            "tab.inactiveBackground",
+            ("BufferLineIndicatorVisible", "bg"),
+        ),
+        (
+            "tab.inactiveBackground",
+            ("BufferLineIndicatorVisible", "fg"),
+        ),
+        // 当前缓冲区
+        ("tab.activeBackground", ("BufferLineBufferSelected", "bg")),
+        ("tab.activeForeground", ("BufferLineBufferSelected", "fg")),
+        (
+            "tab.activeBackground",
+            ("BufferLineCloseButtonSelected", "bg"),
+        ),
+        (
+            "tab.activeForeground",
+            ("BufferLineCloseButtonSelected", "fg"),
+        ),
+        ("tab.activeBackground", ("BufferLineModifiedSelected", "bg")),
+        (
+            "tab.activeModifiedBorder",
+            ("BufferLineModifiedSelected", "fg"),
+        ),
+        (
+            "tab.activeBackground",
+            ("BufferLineSeparatorSelected", "bg"),
+        ),
+        (
+            "editorGroupHeader.tabsBackground",
+            ("BufferLineSeparatorSelected", "fg"),
+        ),
+        (
+            "tab.activeBackground",
+            ("BufferLineIndicatorSelected", "bg"),
+        ),
+        ("focusBorder", ("BufferLineIndicatorSelected", "fg")),
+        ("tab.activeBorderTop", ("BufferLineIndicatorSelected", "fg")),
+        ("tab.activeBackground", ("BufferLineTabSelected", "bg")),
+        ("tab.activeForeground", ("BufferLineTabSelected", "fg")),
+        (
+            "editorGroupHeader.tabsBackground",
+            ("BufferLineTabClose", "bg"),
+        ),
+        ("tab.inactiveForeground", ("BufferLineTabClose", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
@@ -0,0 +1,24 @@
+use super::Integration;
+
+// nvim-cmp，对应 VSCode 的补全建议窗口
+pub static INTEGRATION: Integration = Integration {
+    name: "cmp",
+    mappings: &[
+        ("editorSuggestWidget.foreground", ("CmpItemAbbr", "fg")),
+        (
+            "editorSuggestWidget.highlightForeground",
+            ("CmpItemAbbrMatch", "fg"),
+        ),
+        (
+            "editorSuggestWidget.highlightForeground",
+            ("CmpItemAbbrMatchFuzzy", "fg"),
+        ),
+        ("disabledForeground", ("CmpItemAbbrDeprecated", "fg")),
+        ("editorSuggestWidget.foreground", ("CmpItemKind", "fg")),
+        ("editorSuggestWidget.foreground", ("CmpItemMenu", "fg")),
+        ("descriptionForeground", ("CmpItemMenu", "fg")),
+    ],
+    links: &[],
+    kind_groups: &["CmpItemKind{}"],
+    contrast: &[],
+};
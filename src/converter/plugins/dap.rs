@@ -0,0 +1,88 @@
+use super::Integration;
+
+// nvim-dap 与 nvim-dap-ui，对应 VSCode 的调试视图
+pub static INTEGRATION: Integration = Integration {
+    name: "dap",
+    mappings: &[
+        // 断点与当前栈帧标记
+        ("editorError.foreground", ("DapBreakpoint", "fg")),
+        ("debugIcon.breakpointForeground", ("DapBreakpoint", "fg")),
+        (
+            "debugIcon.breakpointForeground",
+            ("DapBreakpointCondition", "fg"),
+        ),
+        (
+            "debugIcon.breakpointDisabledForeground",
+            ("DapBreakpointRejected", "fg"),
+        ),
+        (
+            "debugIcon.breakpointUnverifiedForeground",
+            ("DapBreakpointRejected", "fg"),
+        ),
+        ("editorInfo.foreground", ("DapLogPoint", "fg")),
+        ("debugConsole.infoForeground", ("DapLogPoint", "fg")),
+        ("editorWarning.foreground", ("DapStopped", "fg")),
+        (
+            "debugIcon.breakpointCurrentStackframeForeground",
+            ("DapStopped", "fg"),
+        ),
+        (
+            "editor.stackFrameHighlightBackground",
+            ("DapStoppedLine", "bg"),
+        ),
+        // 变量视图
+        ("debugTokenExpression.name", ("DapUIVariable", "fg")),
+        ("debugTokenExpression.name", ("DapUIScope", "fg")),
+        ("debugTokenExpression.type", ("DapUIType", "fg")),
+        ("debugTokenExpression.value", ("DapUIValue", "fg")),
+        ("debugTokenExpression.string", ("DapUIModifiedValue", "fg")),
+        ("debugTokenExpression.error", ("DapUIWatchesError", "fg")),
+        ("debugConsole.errorForeground", ("DapUIWatchesError", "fg")),
+        ("debugTokenExpression.value", ("DapUIWatchesValue", "fg")),
+        (
+            "debugConsole.warningForeground",
+            ("DapUIWatchesEmpty", "fg"),
+        ),
+        // 调用栈与断点列表
+        ("debugConsole.infoForeground", ("DapUIThread", "fg")),
+        (
+            "debugIcon.breakpointCurrentStackframeForeground",
+            ("DapUIStoppedThread", "fg"),
+        ),
+        ("debugConsole.sourceForeground", ("DapUISource", "fg")),
+        (
+            "debugConsole.sourceForeground",
+            ("DapUIBreakpointsPath", "fg"),
+        ),
+        (
+            "debugConsole.infoForeground",
+            ("DapUIBreakpointsInfo", "fg"),
+        ),
+        (
+            "debugConsole.infoForeground",
+            ("DapUIBreakpointsCurrentLine", "fg"),
+        ),
+        ("editorLineNumber.foreground", ("DapUILineNumber", "fg")),
+        ("focusBorder", ("DapUIDecoration", "fg")),
+        ("debugToolBar.border", ("DapUIFloatBorder", "fg")),
+        ("disabledForeground", ("DapUIUnavailable", "fg")),
+        // 控制按钮，对应 VSCode 的调试工具栏
+        ("debugToolBar.background", ("DapUIPlayPause", "bg")),
+        ("debugIcon.continueForeground", ("DapUIPlayPause", "fg")),
+        ("debugToolBar.background", ("DapUIRestart", "bg")),
+        ("debugIcon.restartForeground", ("DapUIRestart", "fg")),
+        ("debugToolBar.background", ("DapUIStop", "bg")),
+        ("debugIcon.stopForeground", ("DapUIStop", "fg")),
+        ("debugToolBar.background", ("DapUIStepOver", "bg")),
+        ("debugIcon.stepOverForeground", ("DapUIStepOver", "fg")),
+        ("debugToolBar.background", ("DapUIStepInto", "bg")),
+        ("debugIcon.stepIntoForeground", ("DapUIStepInto", "fg")),
+        ("debugToolBar.background", ("DapUIStepOut", "bg")),
+        ("debugIcon.stepOutForeground", ("DapUIStepOut", "fg")),
+        ("debugToolBar.background", ("DapUIStepBack", "bg")),
+        ("debugIcon.stepBackForeground", ("DapUIStepBack", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
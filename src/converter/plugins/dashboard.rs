@@ -0,0 +1,26 @@
+use super::Integration;
+
+// dashboard-nvim 启动页，对应 VSCode 的欢迎页
+pub static INTEGRATION: Integration = Integration {
+    name: "dashboard",
+    mappings: &[
+        ("textLink.foreground", ("DashboardHeader", "fg")),
+        ("welcomePage.progress.foreground", ("DashboardHeader", "fg")),
+        ("descriptionForeground", ("DashboardFooter", "fg")),
+        ("editor.foreground", ("DashboardDesc", "fg")),
+        ("textLink.activeForeground", ("DashboardKey", "fg")),
+        ("textLink.foreground", ("DashboardIcon", "fg")),
+        ("textLink.activeForeground", ("DashboardShortCut", "fg")),
+        (
+            "walkthrough.stepTitle.foreground",
+            ("DashboardProjectTitle", "fg"),
+        ),
+        (
+            "walkthrough.stepTitle.foreground",
+            ("DashboardMruTitle", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
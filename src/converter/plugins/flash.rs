@@ -0,0 +1,20 @@
+use super::Integration;
+
+// flash.nvim 跳转标签，对应 VSCode 的查找匹配与链接颜色
+pub static INTEGRATION: Integration = Integration {
+    name: "flash",
+    mappings: &[
+        // 没有链接颜色时以当前查找匹配的背景作为标签底色
+        ("editor.findMatchBackground", ("FlashLabel", "bg")),
+        ("editorLink.activeForeground", ("FlashLabel", "bg")),
+        ("editor.background", ("FlashLabel", "fg")),
+        ("editor.findMatchHighlightBackground", ("FlashMatch", "bg")),
+        ("editor.foreground", ("FlashMatch", "fg")),
+        ("editor.findMatchBackground", ("FlashCurrent", "bg")),
+        ("editor.foreground", ("FlashCurrent", "fg")),
+        ("editorLineNumber.foreground", ("FlashBackdrop", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &["FlashLabel", "FlashMatch", "FlashCurrent"],
+};
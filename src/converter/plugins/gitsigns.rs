@@ -0,0 +1,136 @@
+use super::Integration;
+
+// gitsigns.nvim 以及 diff 语法，对应 VSCode 的 editorGutter 与 gitDecoration
+pub static INTEGRATION: Integration = Integration {
+    name: "gitsigns",
+    mappings: &[
+        // 标记列
+        (
+            "gitDecoration.addedResourceForeground",
+            ("GitSignsAdd", "fg"),
+        ),
+        ("editorGutter.addedBackground", ("GitSignsAdd", "fg")),
+        (
+            "gitDecoration.modifiedResourceForeground",
+            ("GitSignsChange", "fg"),
+        ),
+        ("editorGutter.modifiedBackground", ("GitSignsChange", "fg")),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("GitSignsDelete", "fg"),
+        ),
+        ("editorGutter.deletedBackground", ("GitSignsDelete", "fg")),
+        (
+            "gitDecoration.modifiedResourceForeground",
+            ("GitSignsChangedelete", "fg"),
+        ),
+        (
+            "editorGutter.modifiedBackground",
+            ("GitSignsChangedelete", "fg"),
+        ),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("GitSignsTopdelete", "fg"),
+        ),
+        (
+            "editorGutter.deletedBackground",
+            ("GitSignsTopdelete", "fg"),
+        ),
+        (
+            "gitDecoration.untrackedResourceForeground",
+            ("GitSignsUntracked", "fg"),
+        ),
+        // 行号
+        (
+            "gitDecoration.addedResourceForeground",
+            ("GitSignsAddNr", "fg"),
+        ),
+        ("editorGutter.addedBackground", ("GitSignsAddNr", "fg")),
+        (
+            "gitDecoration.modifiedResourceForeground",
+            ("GitSignsChangeNr", "fg"),
+        ),
+        (
+            "editorGutter.modifiedBackground",
+            ("GitSignsChangeNr", "fg"),
+        ),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("GitSignsDeleteNr", "fg"),
+        ),
+        ("editorGutter.deletedBackground", ("GitSignsDeleteNr", "fg")),
+        // 整行
+        ("diffEditor.insertedLineBackground", ("GitSignsAddLn", "bg")),
+        (
+            "diffEditor.removedLineBackground",
+            ("GitSignsDeleteLn", "bg"),
+        ),
+        (
+            "diffEditor.removedLineBackground",
+            ("GitSignsDeleteVirtLn", "bg"),
+        ),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("GitSignsDeleteVirtLn", "fg"),
+        ),
+        // 单词级 diff
+        (
+            "diffEditor.insertedTextBackground",
+            ("GitSignsAddInline", "bg"),
+        ),
+        (
+            "diffEditor.removedTextBackground",
+            ("GitSignsDeleteInline", "bg"),
+        ),
+        (
+            "diffEditor.insertedTextBackground",
+            ("GitSignsAddLnInline", "bg"),
+        ),
+        (
+            "diffEditor.removedTextBackground",
+            ("GitSignsDeleteLnInline", "bg"),
+        ),
+        (
+            "diffEditor.removedTextBackground",
+            ("GitSignsDeleteVirtLnInline", "bg"),
+        ),
+        // 行尾 blame
+        (
+            "gitDecoration.ignoredResourceForeground",
+            ("GitSignsCurrentLineBlame", "fg"),
+        ),
+        (
+            "editorCodeLens.foreground",
+            ("GitSignsCurrentLineBlame", "fg"),
+        ),
+        (
+            "gitlens.trailingLineForegroundColor",
+            ("GitSignsCurrentLineBlame", "fg"),
+        ),
+        // diff / fugitive 语法
+        ("gitDecoration.addedResourceForeground", ("diffAdded", "fg")),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("diffRemoved", "fg"),
+        ),
+        (
+            "gitDecoration.modifiedResourceForeground",
+            ("diffChanged", "fg"),
+        ),
+        (
+            "gitDecoration.addedResourceForeground",
+            ("diffNewFile", "fg"),
+        ),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("diffOldFile", "fg"),
+        ),
+        (
+            "gitDecoration.renamedResourceForeground",
+            ("diffFile", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
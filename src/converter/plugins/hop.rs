@@ -0,0 +1,15 @@
+use super::Integration;
+
+// hop.nvim 跳转标签，只有前景色，直接显示在代码上
+pub static INTEGRATION: Integration = Integration {
+    name: "hop",
+    mappings: &[
+        ("editorLink.activeForeground", ("HopNextKey", "fg")),
+        ("editor.findMatchBackground", ("HopNextKey1", "fg")),
+        ("editor.findMatchHighlightBackground", ("HopNextKey2", "fg")),
+        ("editorLineNumber.foreground", ("HopUnmatched", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &["HopNextKey", "HopNextKey1", "HopNextKey2"],
+};
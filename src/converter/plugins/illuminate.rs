@@ -0,0 +1,14 @@
+use super::Integration;
+
+// vim-illuminate，与 LSP document highlight 使用相同的颜色
+pub static INTEGRATION: Integration = Integration {
+    name: "illuminate",
+    mappings: &[],
+    links: &[
+        ("IlluminatedWordText", "LspReferenceText"),
+        ("IlluminatedWordRead", "LspReferenceRead"),
+        ("IlluminatedWordWrite", "LspReferenceWrite"),
+    ],
+    kind_groups: &[],
+    contrast: &[],
+};
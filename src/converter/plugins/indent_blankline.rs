@@ -0,0 +1,52 @@
+use super::Integration;
+
+// indent-blankline.nvim (v3)，对应 VSCode 的缩进参考线
+pub static INTEGRATION: Integration = Integration {
+    name: "indent-blankline",
+    mappings: &[
+        ("editorIndentGuide.background", ("IblIndent", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope", "fg")),
+        ("editorWhitespace.foreground", ("IblWhitespace", "fg")),
+        // 按层级着色，用于 `indent.highlight` 与 `scope.highlight` 列表
+        // 缺少某一层时退回到第一层或不带序号的颜色
+        ("editorIndentGuide.background", ("IblIndent1", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent1", "fg")),
+        ("editorIndentGuide.background", ("IblIndent2", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent2", "fg")),
+        ("editorIndentGuide.background2", ("IblIndent2", "fg")),
+        ("editorIndentGuide.background", ("IblIndent3", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent3", "fg")),
+        ("editorIndentGuide.background3", ("IblIndent3", "fg")),
+        ("editorIndentGuide.background", ("IblIndent4", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent4", "fg")),
+        ("editorIndentGuide.background4", ("IblIndent4", "fg")),
+        ("editorIndentGuide.background", ("IblIndent5", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent5", "fg")),
+        ("editorIndentGuide.background5", ("IblIndent5", "fg")),
+        ("editorIndentGuide.background", ("IblIndent6", "fg")),
+        ("editorIndentGuide.background1", ("IblIndent6", "fg")),
+        ("editorIndentGuide.background6", ("IblIndent6", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope1", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope1", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope2", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope2", "fg")),
+        ("editorIndentGuide.activeBackground2", ("IblScope2", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope3", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope3", "fg")),
+        ("editorIndentGuide.activeBackground3", ("IblScope3", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope4", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope4", "fg")),
+        ("editorIndentGuide.activeBackground4", ("IblScope4", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope5", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope5", "fg")),
+        ("editorIndentGuide.activeBackground5", ("IblScope5", "fg")),
+        ("editorIndentGuide.activeBackground", ("IblScope6", "fg")),
+        ("editorIndentGuide.activeBackground1", ("IblScope6", "fg")),
+        ("editorIndentGuide.activeBackground6", ("IblScope6", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
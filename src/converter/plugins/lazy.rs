@@ -0,0 +1,27 @@
+use super::Integration;
+
+// lazy.nvim 插件管理窗口，按钮与标题对应 VSCode 的 button 与 badge
+pub static INTEGRATION: Integration = Integration {
+    name: "lazy",
+    mappings: &[
+        ("editorWidget.background", ("LazyNormal", "bg")),
+        ("editorWidget.foreground", ("LazyNormal", "fg")),
+        ("button.background", ("LazyH1", "bg")),
+        ("button.foreground", ("LazyH1", "fg")),
+        ("textLink.foreground", ("LazyH2", "fg")),
+        ("button.secondaryBackground", ("LazyButton", "bg")),
+        ("button.secondaryForeground", ("LazyButton", "fg")),
+        ("button.background", ("LazyButtonActive", "bg")),
+        ("button.foreground", ("LazyButtonActive", "fg")),
+        ("textLink.foreground", ("LazySpecial", "fg")),
+        ("textLink.foreground", ("LazyUrl", "fg")),
+        ("descriptionForeground", ("LazyDimmed", "fg")),
+        ("descriptionForeground", ("LazyComment", "fg")),
+        ("progressBar.background", ("LazyProgressDone", "fg")),
+        ("badge.background", ("LazyProgressTodo", "fg")),
+        ("editorWidget.border", ("LazyProgressTodo", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
@@ -0,0 +1,18 @@
+use super::Integration;
+
+// leap.nvim 跳转标签，对应 VSCode 的查找匹配与链接颜色
+pub static INTEGRATION: Integration = Integration {
+    name: "leap",
+    mappings: &[
+        // 没有链接颜色时以当前查找匹配的背景作为标签底色
+        ("editor.findMatchBackground", ("LeapLabel", "bg")),
+        ("editorLink.activeForeground", ("LeapLabel", "bg")),
+        ("editor.background", ("LeapLabel", "fg")),
+        ("editor.findMatchHighlightBackground", ("LeapMatch", "bg")),
+        ("editor.foreground", ("LeapMatch", "fg")),
+        ("editorLineNumber.foreground", ("LeapBackdrop", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &["LeapLabel", "LeapMatch"],
+};
@@ -0,0 +1,34 @@
+use super::Integration;
+
+// mason.nvim 安装窗口，按钮与标题对应 VSCode 的 button 与 badge
+pub static INTEGRATION: Integration = Integration {
+    name: "mason",
+    mappings: &[
+        ("editorWidget.background", ("MasonNormal", "bg")),
+        ("editorWidget.foreground", ("MasonNormal", "fg")),
+        ("badge.background", ("MasonHeader", "bg")),
+        ("badge.foreground", ("MasonHeader", "fg")),
+        ("button.background", ("MasonHeaderSecondary", "bg")),
+        ("button.foreground", ("MasonHeaderSecondary", "fg")),
+        ("textLink.foreground", ("MasonHighlight", "fg")),
+        (
+            "textLink.activeForeground",
+            ("MasonHighlightSecondary", "fg"),
+        ),
+        ("button.background", ("MasonHighlightBlock", "bg")),
+        ("button.foreground", ("MasonHighlightBlock", "fg")),
+        ("button.background", ("MasonHighlightBlockBold", "bg")),
+        ("button.foreground", ("MasonHighlightBlockBold", "fg")),
+        ("badge.background", ("MasonHighlightBlockSecondary", "bg")),
+        ("badge.foreground", ("MasonHighlightBlockSecondary", "fg")),
+        ("button.secondaryBackground", ("MasonMutedBlock", "bg")),
+        ("button.secondaryForeground", ("MasonMutedBlock", "fg")),
+        ("descriptionForeground", ("MasonMuted", "fg")),
+        ("textLink.foreground", ("MasonLink", "fg")),
+        ("errorForeground", ("MasonError", "fg")),
+        ("editorWarning.foreground", ("MasonWarning", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
@@ -0,0 +1,146 @@
+use super::Integration;
+
+// mini.nvim 各模块
+pub static INTEGRATION: Integration = Integration {
+    name: "mini",
+    mappings: &[
+        // mini.indentscope
+        (
+            "editorIndentGuide.activeBackground",
+            ("MiniIndentscopeSymbol", "fg"),
+        ),
+        (
+            "editorIndentGuide.activeBackground1",
+            ("MiniIndentscopeSymbol", "fg"),
+        ),
+        // mini.statusline，对应 VSCode 的状态栏
+        (
+            "statusBarItem.remoteBackground",
+            ("MiniStatuslineModeNormal", "bg"),
+        ),
+        (
+            "statusBarItem.remoteForeground",
+            ("MiniStatuslineModeNormal", "fg"),
+        ),
+        (
+            "editorGutter.addedBackground",
+            ("MiniStatuslineModeInsert", "bg"),
+        ),
+        ("statusBar.background", ("MiniStatuslineModeInsert", "fg")),
+        (
+            "editorGutter.modifiedBackground",
+            ("MiniStatuslineModeVisual", "bg"),
+        ),
+        ("statusBar.background", ("MiniStatuslineModeVisual", "fg")),
+        (
+            "statusBar.debuggingBackground",
+            ("MiniStatuslineModeReplace", "bg"),
+        ),
+        (
+            "statusBar.debuggingForeground",
+            ("MiniStatuslineModeReplace", "fg"),
+        ),
+        (
+            "editorWarning.foreground",
+            ("MiniStatuslineModeCommand", "bg"),
+        ),
+        ("statusBar.background", ("MiniStatuslineModeCommand", "fg")),
+        (
+            "statusBarItem.hoverBackground",
+            ("MiniStatuslineModeOther", "bg"),
+        ),
+        ("statusBar.foreground", ("MiniStatuslineModeOther", "fg")),
+        (
+            "statusBarItem.hoverBackground",
+            ("MiniStatuslineDevinfo", "bg"),
+        ),
+        ("statusBar.foreground", ("MiniStatuslineDevinfo", "fg")),
+        ("statusBar.background", ("MiniStatuslineFilename", "bg")),
+        ("statusBar.foreground", ("MiniStatuslineFilename", "fg")),
+        (
+            "statusBarItem.hoverBackground",
+            ("MiniStatuslineFileinfo", "bg"),
+        ),
+        ("statusBar.foreground", ("MiniStatuslineFileinfo", "fg")),
+        (
+            "statusBar.noFolderBackground",
+            ("MiniStatuslineInactive", "bg"),
+        ),
+        (
+            "statusBar.noFolderForeground",
+            ("MiniStatuslineInactive", "fg"),
+        ),
+        // mini.files，对应 VSCode 的侧边栏
+        ("sideBar.background", ("MiniFilesNormal", "bg")),
+        ("sideBar.foreground", ("MiniFilesNormal", "fg")),
+        ("sideBar.background", ("MiniFilesBorder", "bg")),
+        ("editorGroup.border", ("MiniFilesBorder", "fg")),
+        ("sideBar.border", ("MiniFilesBorder", "fg")),
+        ("sideBar.background", ("MiniFilesTitle", "bg")),
+        ("sideBarSectionHeader.foreground", ("MiniFilesTitle", "fg")),
+        ("sideBar.background", ("MiniFilesTitleFocused", "bg")),
+        ("sideBarTitle.foreground", ("MiniFilesTitleFocused", "fg")),
+        ("list.highlightForeground", ("MiniFilesDirectory", "fg")),
+        ("symbolIcon.folderForeground", ("MiniFilesDirectory", "fg")),
+        ("sideBar.foreground", ("MiniFilesFile", "fg")),
+        (
+            "list.inactiveSelectionBackground",
+            ("MiniFilesCursorLine", "bg"),
+        ),
+        (
+            "list.activeSelectionBackground",
+            ("MiniFilesCursorLine", "bg"),
+        ),
+        // mini.pick，对应 VSCode 的命令面板
+        ("quickInput.background", ("MiniPickNormal", "bg")),
+        ("quickInput.foreground", ("MiniPickNormal", "fg")),
+        ("quickInput.background", ("MiniPickBorder", "bg")),
+        ("editorWidget.border", ("MiniPickBorder", "fg")),
+        ("quickInput.background", ("MiniPickBorderText", "bg")),
+        ("pickerGroup.foreground", ("MiniPickBorderText", "fg")),
+        ("pickerGroup.foreground", ("MiniPickHeader", "fg")),
+        ("input.background", ("MiniPickPrompt", "bg")),
+        ("input.foreground", ("MiniPickPrompt", "fg")),
+        (
+            "quickInputList.focusBackground",
+            ("MiniPickMatchCurrent", "bg"),
+        ),
+        (
+            "quickInputList.focusForeground",
+            ("MiniPickMatchCurrent", "fg"),
+        ),
+        ("list.highlightForeground", ("MiniPickMatchRanges", "fg")),
+        // mini.diff，对应 VSCode 的 diff 编辑器
+        ("editorGutter.addedBackground", ("MiniDiffSignAdd", "fg")),
+        (
+            "editorGutter.modifiedBackground",
+            ("MiniDiffSignChange", "fg"),
+        ),
+        (
+            "editorGutter.deletedBackground",
+            ("MiniDiffSignDelete", "fg"),
+        ),
+        (
+            "diffEditor.insertedTextBackground",
+            ("MiniDiffOverAdd", "bg"),
+        ),
+        (
+            "diffEditor.insertedTextBackground",
+            ("MiniDiffOverChange", "bg"),
+        ),
+        (
+            "diffEditor.removedTextBackground",
+            ("MiniDiffOverDelete", "bg"),
+        ),
+        (
+            "diffEditor.unchangedCodeBackground",
+            ("MiniDiffOverContext", "bg"),
+        ),
+    ],
+    links: &[
+        // mini.cursorword
+        ("MiniCursorword", "LspReferenceText"),
+    ],
+    kind_groups: &[],
+    contrast: &[],
+};
@@ -0,0 +1,96 @@
+mod aerial;
+mod alpha;
+mod barbar;
+mod blink_cmp;
+mod bufferline;
+mod cmp;
+mod dap;
+mod dashboard;
+mod flash;
+mod gitsigns;
+mod hop;
+mod illuminate;
+mod indent_blankline;
+mod lazy;
+mod leap;
+mod mason;
+mod mini;
+mod navic;
+mod neo_tree;
+mod noice;
+mod notify;
+mod nvim_tree;
+mod outline;
+mod rainbow_delimiters;
+mod snacks;
+mod symbol_kinds;
+mod telescope;
+mod trouble;
+mod which_key;
+
+pub use symbol_kinds::symbol_palette;
+
+/// 第三方插件集成
+///
+/// 每个集成提供自己的映射表，格式与 `EDITOR_MAPPINGS` 相同。
+/// 同一高亮属性出现多次时后面的条目覆盖前面的，因此备选键写在前、首选键写在后。
+pub struct Integration {
+    /// 集成名，用于 `--plugins` 选择以及生成的 Lua 中运行时开关
+    pub name: &'static str,
+    pub mappings: &'static [(&'static str, (&'static str, &'static str))],
+    /// 高亮组 -> 链接目标，目标组存在时才生成
+    pub links: &'static [(&'static str, &'static str)],
+    /// 按符号类型着色的高亮组模板，`{}` 替换为类型名，
+    /// 如 `CmpItemKind{}` 生成 `CmpItemKindFunction` 等
+    pub kind_groups: &'static [&'static str],
+    /// 需要保证可读性的高亮组，前景色与背景色 (缺省为 Normal 背景) 对比度不足时自动调整
+    pub contrast: &'static [&'static str],
+}
+
+/// 已注册的集成，按顺序应用
+pub static INTEGRATIONS: &[&Integration] = &[
+    &telescope::INTEGRATION,
+    &gitsigns::INTEGRATION,
+    &nvim_tree::INTEGRATION,
+    &neo_tree::INTEGRATION,
+    &cmp::INTEGRATION,
+    &blink_cmp::INTEGRATION,
+    &bufferline::INTEGRATION,
+    &barbar::INTEGRATION,
+    &rainbow_delimiters::INTEGRATION,
+    &indent_blankline::INTEGRATION,
+    &mini::INTEGRATION,
+    &which_key::INTEGRATION,
+    &noice::INTEGRATION,
+    &notify::INTEGRATION,
+    &dap::INTEGRATION,
+    &navic::INTEGRATION,
+    &aerial::INTEGRATION,
+    &outline::INTEGRATION,
+    &trouble::INTEGRATION,
+    &snacks::INTEGRATION,
+    &flash::INTEGRATION,
+    &leap::INTEGRATION,
+    &hop::INTEGRATION,
+    &illuminate::INTEGRATION,
+    &lazy::INTEGRATION,
+    &mason::INTEGRATION,
+    &alpha::INTEGRATION,
+    &dashboard::INTEGRATION,
+];
+
+/// 根据名称查找集成
+pub fn find_integration(name: &str) -> Option<&'static Integration> {
+    INTEGRATIONS
+        .iter()
+        .find(|integration| integration.name == name)
+        .copied()
+}
+
+/// 所有集成名称
+pub fn integration_names() -> Vec<&'static str> {
+    INTEGRATIONS
+        .iter()
+        .map(|integration| integration.name)
+        .collect()
+}
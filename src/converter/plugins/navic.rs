@@ -0,0 +1,15 @@
+use super::Integration;
+
+// nvim-navic 面包屑，对应 VSCode 的 breadcrumb
+pub static INTEGRATION: Integration = Integration {
+    name: "navic",
+    mappings: &[
+        ("breadcrumb.foreground", ("NavicText", "fg")),
+        ("breadcrumb.background", ("NavicText", "bg")),
+        ("breadcrumb.foreground", ("NavicSeparator", "fg")),
+        ("breadcrumb.background", ("NavicSeparator", "bg")),
+    ],
+    links: &[],
+    kind_groups: &["NavicIcons{}"],
+    contrast: &[],
+};
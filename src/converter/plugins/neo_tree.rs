@@ -0,0 +1,94 @@
+use super::Integration;
+
+// neo-tree.nvim，对应 VSCode 的侧边栏资源管理器
+pub static INTEGRATION: Integration = Integration {
+    name: "neo-tree",
+    mappings: &[
+        // 窗口
+        ("sideBar.background", ("NeoTreeNormal", "bg")),
+        ("sideBar.foreground", ("NeoTreeNormal", "fg")),
+        ("sideBar.background", ("NeoTreeNormalNC", "bg")),
+        ("sideBar.foreground", ("NeoTreeNormalNC", "fg")),
+        ("sideBar.background", ("NeoTreeEndOfBuffer", "bg")),
+        ("sideBar.background", ("NeoTreeEndOfBuffer", "fg")),
+        ("sideBar.background", ("NeoTreeWinSeparator", "bg")),
+        ("editorGroup.border", ("NeoTreeWinSeparator", "fg")),
+        ("sideBar.border", ("NeoTreeWinSeparator", "fg")),
+        (
+            "list.inactiveSelectionBackground",
+            ("NeoTreeCursorLine", "bg"),
+        ),
+        (
+            "list.activeSelectionBackground",
+            ("NeoTreeCursorLine", "bg"),
+        ),
+        // 标题栏与标签
+        ("sideBarSectionHeader.background", ("NeoTreeTitleBar", "bg")),
+        ("sideBarSectionHeader.foreground", ("NeoTreeTitleBar", "fg")),
+        ("sideBar.background", ("NeoTreeTabActive", "bg")),
+        ("sideBarTitle.foreground", ("NeoTreeTabActive", "fg")),
+        (
+            "sideBarSectionHeader.background",
+            ("NeoTreeTabInactive", "bg"),
+        ),
+        (
+            "sideBarSectionHeader.foreground",
+            ("NeoTreeTabInactive", "fg"),
+        ),
+        ("sideBar.background", ("NeoTreeTabSeparatorActive", "bg")),
+        ("sideBar.background", ("NeoTreeTabSeparatorActive", "fg")),
+        (
+            "sideBarSectionHeader.background",
+            ("NeoTreeTabSeparatorInactive", "bg"),
+        ),
+        ("sideBar.background", ("NeoTreeTabSeparatorInactive", "fg")),
+        // 目录与文件
+        ("sideBarTitle.foreground", ("NeoTreeRootName", "fg")),
+        ("sideBarSectionHeader.foreground", ("NeoTreeRootName", "fg")),
+        ("sideBar.foreground", ("NeoTreeFileName", "fg")),
+        ("sideBar.foreground", ("NeoTreeDirectoryName", "fg")),
+        ("list.highlightForeground", ("NeoTreeDirectoryIcon", "fg")),
+        (
+            "symbolIcon.folderForeground",
+            ("NeoTreeDirectoryIcon", "fg"),
+        ),
+        ("tree.indentGuidesStroke", ("NeoTreeIndentMarker", "fg")),
+        ("tree.indentGuidesStroke", ("NeoTreeExpander", "fg")),
+        // git 状态
+        (
+            "gitDecoration.addedResourceForeground",
+            ("NeoTreeGitAdded", "fg"),
+        ),
+        (
+            "gitDecoration.modifiedResourceForeground",
+            ("NeoTreeGitModified", "fg"),
+        ),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("NeoTreeGitDeleted", "fg"),
+        ),
+        (
+            "gitDecoration.conflictingResourceForeground",
+            ("NeoTreeGitConflict", "fg"),
+        ),
+        (
+            "gitDecoration.renamedResourceForeground",
+            ("NeoTreeGitRenamed", "fg"),
+        ),
+        (
+            "gitDecoration.untrackedResourceForeground",
+            ("NeoTreeGitUntracked", "fg"),
+        ),
+        (
+            "gitDecoration.ignoredResourceForeground",
+            ("NeoTreeGitIgnored", "fg"),
+        ),
+        (
+            "gitDecoration.stageModifiedResourceForeground",
+            ("NeoTreeGitStaged", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
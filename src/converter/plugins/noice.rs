@@ -0,0 +1,69 @@
+use super::Integration;
+
+// noice.nvim，命令行弹窗对应 VSCode 的命令中心
+pub static INTEGRATION: Integration = Integration {
+    name: "noice",
+    mappings: &[
+        // 命令行弹窗
+        ("editorWidget.background", ("NoiceCmdlinePopup", "bg")),
+        ("editorWidget.foreground", ("NoiceCmdlinePopup", "fg")),
+        ("commandCenter.background", ("NoiceCmdlinePopup", "bg")),
+        ("commandCenter.foreground", ("NoiceCmdlinePopup", "fg")),
+        ("editorWidget.background", ("NoiceCmdlinePopupBorder", "bg")),
+        ("editorWidget.border", ("NoiceCmdlinePopupBorder", "fg")),
+        (
+            "commandCenter.background",
+            ("NoiceCmdlinePopupBorder", "bg"),
+        ),
+        ("commandCenter.border", ("NoiceCmdlinePopupBorder", "fg")),
+        (
+            "commandCenter.activeForeground",
+            ("NoiceCmdlinePopupTitle", "fg"),
+        ),
+        ("focusBorder", ("NoiceCmdlineIcon", "fg")),
+        ("editorWarning.foreground", ("NoiceCmdlineIconSearch", "fg")),
+        (
+            "notificationsWarningIcon.foreground",
+            ("NoiceCmdlineIconSearch", "fg"),
+        ),
+        // 通用弹窗
+        ("editorWidget.background", ("NoicePopup", "bg")),
+        ("editorWidget.foreground", ("NoicePopup", "fg")),
+        ("editorWidget.background", ("NoicePopupBorder", "bg")),
+        ("editorWidget.border", ("NoicePopupBorder", "fg")),
+        ("editorWidget.background", ("NoiceConfirm", "bg")),
+        ("editorWidget.foreground", ("NoiceConfirm", "fg")),
+        ("editorWidget.background", ("NoiceConfirmBorder", "bg")),
+        ("editorWidget.border", ("NoiceConfirmBorder", "fg")),
+        ("notifications.background", ("NoiceMini", "bg")),
+        ("notifications.foreground", ("NoiceMini", "fg")),
+        // LSP 进度
+        ("notifications.foreground", ("NoiceLspProgressTitle", "fg")),
+        ("editorInfo.foreground", ("NoiceLspProgressClient", "fg")),
+        (
+            "notificationsInfoIcon.foreground",
+            ("NoiceLspProgressClient", "fg"),
+        ),
+        (
+            "notificationsInfoIcon.foreground",
+            ("NoiceLspProgressSpinner", "fg"),
+        ),
+        ("progressBar.background", ("NoiceLspProgressSpinner", "fg")),
+        ("progressBar.background", ("NoiceFormatProgressDone", "bg")),
+        (
+            "notifications.foreground",
+            ("NoiceFormatProgressDone", "fg"),
+        ),
+        (
+            "notifications.background",
+            ("NoiceFormatProgressTodo", "bg"),
+        ),
+        (
+            "notifications.foreground",
+            ("NoiceFormatProgressTodo", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
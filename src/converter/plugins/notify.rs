@@ -0,0 +1,104 @@
+use super::Integration;
+
+// nvim-notify，对应 VSCode 的通知弹窗
+pub static INTEGRATION: Integration = Integration {
+    name: "notify",
+    mappings: &[
+        ("notifications.background", ("NotifyBackground", "bg")),
+        // ERROR
+        ("notifications.border", ("NotifyERRORBorder", "fg")),
+        ("editorError.foreground", ("NotifyERRORBorder", "fg")),
+        ("problemsErrorIcon.foreground", ("NotifyERRORBorder", "fg")),
+        (
+            "notificationsErrorIcon.foreground",
+            ("NotifyERRORBorder", "fg"),
+        ),
+        ("notifications.background", ("NotifyERRORBorder", "bg")),
+        ("notifications.foreground", ("NotifyERRORIcon", "fg")),
+        ("editorError.foreground", ("NotifyERRORIcon", "fg")),
+        ("problemsErrorIcon.foreground", ("NotifyERRORIcon", "fg")),
+        (
+            "notificationsErrorIcon.foreground",
+            ("NotifyERRORIcon", "fg"),
+        ),
+        ("notifications.foreground", ("NotifyERRORTitle", "fg")),
+        ("editorError.foreground", ("NotifyERRORTitle", "fg")),
+        ("problemsErrorIcon.foreground", ("NotifyERRORTitle", "fg")),
+        (
+            "notificationsErrorIcon.foreground",
+            ("NotifyERRORTitle", "fg"),
+        ),
+        ("notifications.background", ("NotifyERRORBody", "bg")),
+        ("notifications.foreground", ("NotifyERRORBody", "fg")),
+        // WARN
+        ("notifications.border", ("NotifyWARNBorder", "fg")),
+        ("editorWarning.foreground", ("NotifyWARNBorder", "fg")),
+        ("problemsWarningIcon.foreground", ("NotifyWARNBorder", "fg")),
+        (
+            "notificationsWarningIcon.foreground",
+            ("NotifyWARNBorder", "fg"),
+        ),
+        ("notifications.background", ("NotifyWARNBorder", "bg")),
+        ("notifications.foreground", ("NotifyWARNIcon", "fg")),
+        ("editorWarning.foreground", ("NotifyWARNIcon", "fg")),
+        ("problemsWarningIcon.foreground", ("NotifyWARNIcon", "fg")),
+        (
+            "notificationsWarningIcon.foreground",
+            ("NotifyWARNIcon", "fg"),
+        ),
+        ("notifications.foreground", ("NotifyWARNTitle", "fg")),
+        ("editorWarning.foreground", ("NotifyWARNTitle", "fg")),
+        ("problemsWarningIcon.foreground", ("NotifyWARNTitle", "fg")),
+        (
+            "notificationsWarningIcon.foreground",
+            ("NotifyWARNTitle", "fg"),
+        ),
+        ("notifications.background", ("NotifyWARNBody", "bg")),
+        ("notifications.foreground", ("NotifyWARNBody", "fg")),
+        // INFO
+        ("notifications.border", ("NotifyINFOBorder", "fg")),
+        ("editorInfo.foreground", ("NotifyINFOBorder", "fg")),
+        ("problemsInfoIcon.foreground", ("NotifyINFOBorder", "fg")),
+        (
+            "notificationsInfoIcon.foreground",
+            ("NotifyINFOBorder", "fg"),
+        ),
+        ("notifications.background", ("NotifyINFOBorder", "bg")),
+        ("notifications.foreground", ("NotifyINFOIcon", "fg")),
+        ("editorInfo.foreground", ("NotifyINFOIcon", "fg")),
+        ("problemsInfoIcon.foreground", ("NotifyINFOIcon", "fg")),
+        ("notificationsInfoIcon.foreground", ("NotifyINFOIcon", "fg")),
+        ("notifications.foreground", ("NotifyINFOTitle", "fg")),
+        ("editorInfo.foreground", ("NotifyINFOTitle", "fg")),
+        ("problemsInfoIcon.foreground", ("NotifyINFOTitle", "fg")),
+        (
+            "notificationsInfoIcon.foreground",
+            ("NotifyINFOTitle", "fg"),
+        ),
+        ("notifications.background", ("NotifyINFOBody", "bg")),
+        ("notifications.foreground", ("NotifyINFOBody", "fg")),
+        // DEBUG
+        ("notifications.border", ("NotifyDEBUGBorder", "fg")),
+        ("descriptionForeground", ("NotifyDEBUGBorder", "fg")),
+        ("notifications.background", ("NotifyDEBUGBorder", "bg")),
+        ("notifications.foreground", ("NotifyDEBUGIcon", "fg")),
+        ("descriptionForeground", ("NotifyDEBUGIcon", "fg")),
+        ("notifications.foreground", ("NotifyDEBUGTitle", "fg")),
+        ("descriptionForeground", ("NotifyDEBUGTitle", "fg")),
+        ("notifications.background", ("NotifyDEBUGBody", "bg")),
+        ("notifications.foreground", ("NotifyDEBUGBody", "fg")),
+        // TRACE
+        ("notifications.border", ("NotifyTRACEBorder", "fg")),
+        ("textLink.foreground", ("NotifyTRACEBorder", "fg")),
+        ("notifications.background", ("NotifyTRACEBorder", "bg")),
+        ("notifications.foreground", ("NotifyTRACEIcon", "fg")),
+        ("textLink.foreground", ("NotifyTRACEIcon", "fg")),
+        ("notifications.foreground", ("NotifyTRACETitle", "fg")),
+        ("textLink.foreground", ("NotifyTRACETitle", "fg")),
+        ("notifications.background", ("NotifyTRACEBody", "bg")),
+        ("notifications.foreground", ("NotifyTRACEBody", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
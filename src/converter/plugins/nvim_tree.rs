@@ -0,0 +1,73 @@
+use super::Integration;
+
+// nvim-tree.lua，对应 VSCode 的侧边栏资源管理器
+pub static INTEGRATION: Integration = Integration {
+    name: "nvim-tree",
+    mappings: &[
+        // 窗口
+        ("sideBar.background", ("NvimTreeNormal", "bg")),
+        ("sideBar.foreground", ("NvimTreeNormal", "fg")),
+        ("sideBar.background", ("NvimTreeNormalNC", "bg")),
+        ("sideBar.foreground", ("NvimTreeNormalNC", "fg")),
+        ("sideBar.background", ("NvimTreeEndOfBuffer", "bg")),
+        ("sideBar.background", ("NvimTreeEndOfBuffer", "fg")),
+        ("sideBar.background", ("NvimTreeWinSeparator", "bg")),
+        ("editorGroup.border", ("NvimTreeWinSeparator", "fg")),
+        ("sideBar.border", ("NvimTreeWinSeparator", "fg")),
+        (
+            "list.inactiveSelectionBackground",
+            ("NvimTreeCursorLine", "bg"),
+        ),
+        (
+            "list.activeSelectionBackground",
+            ("NvimTreeCursorLine", "bg"),
+        ),
+        // 目录与文件
+        ("sideBarTitle.foreground", ("NvimTreeRootFolder", "fg")),
+        (
+            "sideBarSectionHeader.foreground",
+            ("NvimTreeRootFolder", "fg"),
+        ),
+        ("sideBar.foreground", ("NvimTreeFolderName", "fg")),
+        ("sideBar.foreground", ("NvimTreeEmptyFolderName", "fg")),
+        (
+            "list.highlightForeground",
+            ("NvimTreeOpenedFolderName", "fg"),
+        ),
+        ("list.highlightForeground", ("NvimTreeFolderIcon", "fg")),
+        ("symbolIcon.folderForeground", ("NvimTreeFolderIcon", "fg")),
+        ("tree.indentGuidesStroke", ("NvimTreeIndentMarker", "fg")),
+        // git 状态
+        (
+            "gitDecoration.untrackedResourceForeground",
+            ("NvimTreeGitNew", "fg"),
+        ),
+        (
+            "gitDecoration.modifiedResourceForeground",
+            ("NvimTreeGitDirty", "fg"),
+        ),
+        (
+            "gitDecoration.deletedResourceForeground",
+            ("NvimTreeGitDeleted", "fg"),
+        ),
+        (
+            "gitDecoration.stageModifiedResourceForeground",
+            ("NvimTreeGitStaged", "fg"),
+        ),
+        (
+            "gitDecoration.conflictingResourceForeground",
+            ("NvimTreeGitMerge", "fg"),
+        ),
+        (
+            "gitDecoration.renamedResourceForeground",
+            ("NvimTreeGitRenamed", "fg"),
+        ),
+        (
+            "gitDecoration.ignoredResourceForeground",
+            ("NvimTreeGitIgnored", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
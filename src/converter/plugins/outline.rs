@@ -0,0 +1,23 @@
+use super::Integration;
+
+// outline.nvim 大纲窗口
+// 符号图标需在 outline.nvim 的 `symbols.icons[kind].hl` 中指向 `Outline<Kind>` 才会生效
+pub static INTEGRATION: Integration = Integration {
+    name: "outline",
+    mappings: &[
+        ("list.inactiveSelectionBackground", ("OutlineCurrent", "bg")),
+        ("list.activeSelectionBackground", ("OutlineCurrent", "bg")),
+        ("list.activeSelectionForeground", ("OutlineCurrent", "fg")),
+        ("tree.indentGuidesStroke", ("OutlineGuides", "fg")),
+        ("tree.indentGuidesStroke", ("OutlineFoldMarker", "fg")),
+        ("descriptionForeground", ("OutlineDetails", "fg")),
+        ("editorLineNumber.foreground", ("OutlineLineno", "fg")),
+        (
+            "editor.rangeHighlightBackground",
+            ("OutlineJumpHighlight", "bg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &["Outline{}"],
+    contrast: &[],
+};
@@ -0,0 +1,41 @@
+use super::Integration;
+
+// rainbow-delimiters.nvim，对应 VSCode 的括号对着色
+// rainbow-delimiters 循环 7 个分组而 VSCode 循环 6 种颜色，Cyan 只是复用 foreground1
+// 第 8 层起两者不再一致，需要完全一致时在 `highlight` 中只列前 6 个分组
+pub static INTEGRATION: Integration = Integration {
+    name: "rainbow-delimiters",
+    mappings: &[
+        (
+            "editorBracketHighlight.foreground1",
+            ("RainbowDelimiterRed", "fg"),
+        ),
+        (
+            "editorBracketHighlight.foreground2",
+            ("RainbowDelimiterYellow", "fg"),
+        ),
+        (
+            "editorBracketHighlight.foreground3",
+            ("RainbowDelimiterBlue", "fg"),
+        ),
+        (
+            "editorBracketHighlight.foreground4",
+            ("RainbowDelimiterOrange", "fg"),
+        ),
+        (
+            "editorBracketHighlight.foreground5",
+            ("RainbowDelimiterGreen", "fg"),
+        ),
+        (
+            "editorBracketHighlight.foreground6",
+            ("RainbowDelimiterViolet", "fg"),
+        ),
+        (
+            "editorBracketHighlight.foreground1",
+            ("RainbowDelimiterCyan", "fg"),
+        ),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
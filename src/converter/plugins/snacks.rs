@@ -0,0 +1,53 @@
+use super::Integration;
+
+// snacks.nvim 的 dashboard 与 picker
+pub static INTEGRATION: Integration = Integration {
+    name: "snacks",
+    mappings: &[
+        // dashboard，对应 VSCode 的欢迎页
+        ("editor.background", ("SnacksDashboardNormal", "bg")),
+        ("editor.foreground", ("SnacksDashboardNormal", "fg")),
+        ("welcomePage.background", ("SnacksDashboardNormal", "bg")),
+        ("textLink.foreground", ("SnacksDashboardHeader", "fg")),
+        (
+            "welcomePage.progress.foreground",
+            ("SnacksDashboardHeader", "fg"),
+        ),
+        ("editor.foreground", ("SnacksDashboardTitle", "fg")),
+        (
+            "walkthrough.stepTitle.foreground",
+            ("SnacksDashboardTitle", "fg"),
+        ),
+        ("descriptionForeground", ("SnacksDashboardDesc", "fg")),
+        ("descriptionForeground", ("SnacksDashboardFooter", "fg")),
+        ("textLink.foreground", ("SnacksDashboardIcon", "fg")),
+        ("textLink.activeForeground", ("SnacksDashboardKey", "fg")),
+        (
+            "textLink.activeForeground",
+            ("SnacksDashboardSpecial", "fg"),
+        ),
+        // picker，对应 VSCode 的命令面板
+        ("quickInput.background", ("SnacksPicker", "bg")),
+        ("quickInput.foreground", ("SnacksPicker", "fg")),
+        ("quickInput.background", ("SnacksPickerBorder", "bg")),
+        ("editorWidget.border", ("SnacksPickerBorder", "fg")),
+        ("quickInputTitle.background", ("SnacksPickerTitle", "bg")),
+        ("pickerGroup.foreground", ("SnacksPickerTitle", "fg")),
+        ("input.background", ("SnacksPickerInput", "bg")),
+        ("input.foreground", ("SnacksPickerInput", "fg")),
+        ("input.background", ("SnacksPickerInputBorder", "bg")),
+        ("input.border", ("SnacksPickerInputBorder", "fg")),
+        ("focusBorder", ("SnacksPickerPrompt", "fg")),
+        (
+            "quickInputList.focusBackground",
+            ("SnacksPickerListCursorLine", "bg"),
+        ),
+        ("list.highlightForeground", ("SnacksPickerMatch", "fg")),
+        ("descriptionForeground", ("SnacksPickerDir", "fg")),
+        ("editor.background", ("SnacksPickerPreview", "bg")),
+        ("editor.foreground", ("SnacksPickerPreview", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::types::HighlightGroup;
+
+// LSP 符号/补全项类型 -> (VSCode symbolIcon 颜色键, 缺少该键时使用的语法高亮组)
+static SYMBOL_KINDS: &[(&str, (&str, &str))] = &[
+    ("Text", ("symbolIcon.textForeground", "Normal")),
+    ("Method", ("symbolIcon.methodForeground", "Function")),
+    ("Function", ("symbolIcon.functionForeground", "Function")),
+    (
+        "Constructor",
+        ("symbolIcon.constructorForeground", "Function"),
+    ),
+    ("Field", ("symbolIcon.fieldForeground", "Identifier")),
+    ("Variable", ("symbolIcon.variableForeground", "Identifier")),
+    ("Class", ("symbolIcon.classForeground", "Type")),
+    ("Interface", ("symbolIcon.interfaceForeground", "Type")),
+    ("Module", ("symbolIcon.moduleForeground", "Identifier")),
+    (
+        "Namespace",
+        ("symbolIcon.namespaceForeground", "Identifier"),
+    ),
+    ("Package", ("symbolIcon.packageForeground", "Identifier")),
+    ("Property", ("symbolIcon.propertyForeground", "Identifier")),
+    ("Unit", ("symbolIcon.unitForeground", "Number")),
+    ("Value", ("symbolIcon.constantForeground", "Constant")),
+    ("Enum", ("symbolIcon.enumeratorForeground", "Type")),
+    ("Keyword", ("symbolIcon.keywordForeground", "Keyword")),
+    ("Snippet", ("symbolIcon.snippetForeground", "Special")),
+    ("Color", ("symbolIcon.colorForeground", "Constant")),
+    ("File", ("symbolIcon.fileForeground", "Normal")),
+    (
+        "Reference",
+        ("symbolIcon.referenceForeground", "Identifier"),
+    ),
+    ("Folder", ("symbolIcon.folderForeground", "Normal")),
+    (
+        "EnumMember",
+        ("symbolIcon.enumeratorMemberForeground", "Constant"),
+    ),
+    ("Constant", ("symbolIcon.constantForeground", "Constant")),
+    ("Struct", ("symbolIcon.structForeground", "Structure")),
+    ("Event", ("symbolIcon.eventForeground", "Special")),
+    ("Operator", ("symbolIcon.operatorForeground", "Operator")),
+    (
+        "TypeParameter",
+        ("symbolIcon.typeParameterForeground", "Type"),
+    ),
+    ("String", ("symbolIcon.stringForeground", "String")),
+    ("Number", ("symbolIcon.numberForeground", "Number")),
+    ("Boolean", ("symbolIcon.booleanForeground", "Constant")),
+    ("Array", ("symbolIcon.arrayForeground", "Type")),
+    ("Object", ("symbolIcon.objectForeground", "Type")),
+    ("Key", ("symbolIcon.keyForeground", "Identifier")),
+    ("Null", ("symbolIcon.nullForeground", "Constant")),
+];
+
+/// 构建符号类型调色板 (类型名 -> 颜色)
+///
+/// 优先使用主题的 `symbolIcon.*Foreground`，缺失时退回到对应语法高亮组的前景色，
+/// 两者都没有的类型不出现在结果中。
+pub fn symbol_palette(
+    colors: &HashMap<String, String>,
+    highlights: &HashMap<String, HighlightGroup>,
+) -> Vec<(&'static str, String)> {
+    SYMBOL_KINDS
+        .iter()
+        .filter_map(|&(kind, (vs_key, syntax_group))| {
+            colors
+                .get(vs_key)
+                .cloned()
+                .or_else(|| highlights.get(syntax_group).and_then(|hl| hl.fg.clone()))
+                .map(|color| (kind, color))
+        })
+        .collect()
+}
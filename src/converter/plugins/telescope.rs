@@ -0,0 +1,81 @@
+use super::Integration;
+
+// telescope.nvim，对应 VSCode 的命令面板 (quickInput)
+pub static INTEGRATION: Integration = Integration {
+    name: "telescope",
+    mappings: &[
+        // 整体窗口
+        ("editorWidget.background", ("TelescopeNormal", "bg")),
+        ("editorWidget.foreground", ("TelescopeNormal", "fg")),
+        ("quickInput.background", ("TelescopeNormal", "bg")),
+        ("quickInput.foreground", ("TelescopeNormal", "fg")),
+        ("editorWidget.background", ("TelescopeBorder", "bg")),
+        ("quickInput.background", ("TelescopeBorder", "bg")),
+        ("focusBorder", ("TelescopeBorder", "fg")),
+        ("editorWidget.border", ("TelescopeBorder", "fg")),
+        // 输入框
+        ("input.background", ("TelescopePromptNormal", "bg")),
+        ("input.foreground", ("TelescopePromptNormal", "fg")),
+        ("input.background", ("TelescopePromptBorder", "bg")),
+        ("input.border", ("TelescopePromptBorder", "fg")),
+        ("input.background", ("TelescopePromptPrefix", "bg")),
+        ("focusBorder", ("TelescopePromptPrefix", "fg")),
+        ("input.background", ("TelescopePromptCounter", "bg")),
+        (
+            "input.placeholderForeground",
+            ("TelescopePromptCounter", "fg"),
+        ),
+        // 结果列表
+        ("quickInput.background", ("TelescopeResultsNormal", "bg")),
+        ("quickInput.foreground", ("TelescopeResultsNormal", "fg")),
+        ("quickInput.background", ("TelescopeResultsBorder", "bg")),
+        ("editorWidget.border", ("TelescopeResultsBorder", "fg")),
+        (
+            "list.activeSelectionBackground",
+            ("TelescopeSelection", "bg"),
+        ),
+        (
+            "list.activeSelectionForeground",
+            ("TelescopeSelection", "fg"),
+        ),
+        (
+            "quickInputList.focusBackground",
+            ("TelescopeSelection", "bg"),
+        ),
+        (
+            "quickInputList.focusForeground",
+            ("TelescopeSelection", "fg"),
+        ),
+        (
+            "quickInputList.focusBackground",
+            ("TelescopeSelectionCaret", "bg"),
+        ),
+        ("focusBorder", ("TelescopeSelectionCaret", "fg")),
+        ("list.highlightForeground", ("TelescopeMatching", "fg")),
+        (
+            "list.activeSelectionForeground",
+            ("TelescopeMultiSelection", "fg"),
+        ),
+        // 预览窗口
+        ("editor.background", ("TelescopePreviewNormal", "bg")),
+        ("editor.foreground", ("TelescopePreviewNormal", "fg")),
+        ("editor.background", ("TelescopePreviewBorder", "bg")),
+        ("editorWidget.border", ("TelescopePreviewBorder", "fg")),
+        // 标题
+        ("quickInputTitle.background", ("TelescopePromptTitle", "bg")),
+        ("pickerGroup.foreground", ("TelescopePromptTitle", "fg")),
+        (
+            "quickInputTitle.background",
+            ("TelescopeResultsTitle", "bg"),
+        ),
+        ("pickerGroup.foreground", ("TelescopeResultsTitle", "fg")),
+        (
+            "quickInputTitle.background",
+            ("TelescopePreviewTitle", "bg"),
+        ),
+        ("pickerGroup.foreground", ("TelescopePreviewTitle", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
@@ -0,0 +1,21 @@
+use super::Integration;
+
+// trouble.nvim (v3)，对应 VSCode 的问题面板
+pub static INTEGRATION: Integration = Integration {
+    name: "trouble",
+    mappings: &[
+        ("panel.background", ("TroubleNormal", "bg")),
+        ("editor.foreground", ("TroubleNormal", "fg")),
+        ("panel.background", ("TroubleNormalNC", "bg")),
+        ("editor.foreground", ("TroubleNormalNC", "fg")),
+        ("editor.foreground", ("TroubleText", "fg")),
+        ("badge.background", ("TroubleCount", "bg")),
+        ("badge.foreground", ("TroubleCount", "fg")),
+        ("editorLineNumber.foreground", ("TroublePos", "fg")),
+        ("descriptionForeground", ("TroubleSource", "fg")),
+        ("tree.indentGuidesStroke", ("TroubleIndent", "fg")),
+    ],
+    links: &[],
+    kind_groups: &["TroubleIcon{}"],
+    contrast: &[],
+};
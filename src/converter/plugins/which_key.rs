@@ -0,0 +1,26 @@
+use super::Integration;
+
+// which-key.nvim，对应 VSCode 的命令中心与编辑器小部件
+pub static INTEGRATION: Integration = Integration {
+    name: "which-key",
+    mappings: &[
+        ("editorWidget.background", ("WhichKeyNormal", "bg")),
+        ("editorWidget.foreground", ("WhichKeyNormal", "fg")),
+        ("editorWidget.background", ("WhichKeyBorder", "bg")),
+        ("editorWidget.border", ("WhichKeyBorder", "fg")),
+        ("editorWidget.background", ("WhichKeyTitle", "bg")),
+        ("commandCenter.foreground", ("WhichKeyTitle", "fg")),
+        ("focusBorder", ("WhichKey", "fg")),
+        ("commandCenter.activeForeground", ("WhichKey", "fg")),
+        ("focusBorder", ("WhichKeyGroup", "fg")),
+        ("editorWidget.foreground", ("WhichKeyDesc", "fg")),
+        (
+            "commandCenter.inactiveForeground",
+            ("WhichKeySeparator", "fg"),
+        ),
+        ("commandCenter.inactiveForeground", ("WhichKeyValue", "fg")),
+    ],
+    links: &[],
+    kind_groups: &[],
+    contrast: &[],
+};
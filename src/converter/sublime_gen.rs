@@ -0,0 +1,293 @@
+use anyhow::Result;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::token::TokenScope;
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// 全局颜色映射 (VSCode 键, sublime-color-scheme 键, tmTheme 键)
+// 同一键后面的条目覆盖前面的
+static GLOBAL_MAPPINGS: &[(&str, &str, &str)] = &[
+    ("editor.foreground", "foreground", "foreground"),
+    ("editorCursor.foreground", "caret", "caret"),
+    (
+        "editor.lineHighlightBackground",
+        "line_highlight",
+        "lineHighlight",
+    ),
+    ("editor.selectionBackground", "selection", "selection"),
+    (
+        "editor.selectionForeground",
+        "selection_foreground",
+        "selectionForeground",
+    ),
+    (
+        "editor.inactiveSelectionBackground",
+        "inactive_selection",
+        "inactiveSelection",
+    ),
+    (
+        "editor.findMatchHighlightBackground",
+        "find_highlight",
+        "findHighlight",
+    ),
+    ("editor.background", "gutter", "gutter"),
+    ("editorGutter.background", "gutter", "gutter"),
+    (
+        "editorLineNumber.foreground",
+        "gutter_foreground",
+        "gutterForeground",
+    ),
+    ("editorWhitespace.foreground", "invisibles", "invisibles"),
+    ("editorIndentGuide.background", "guide", "guide"),
+    (
+        "editorIndentGuide.activeBackground",
+        "active_guide",
+        "activeGuide",
+    ),
+    ("focusBorder", "accent", "accent"),
+];
+
+// tokenColors 中的一条规则，颜色已与编辑器背景混合
+struct Rule {
+    name: String,
+    scope: String,
+    foreground: Option<String>,
+    background: Option<String>,
+    font_style: Option<String>,
+}
+
+/// TextMate 兼容的配色，供 Sublime Text、bat 与 delta 使用
+pub struct SublimeGenerator<'a> {
+    theme: &'a ThemeConfig,
+    // (sublime-color-scheme 键, tmTheme 键, 颜色)，背景色在最前
+    globals: Vec<(&'static str, &'static str, String)>,
+    rules: Vec<Rule>,
+}
+
+impl<'a> SublimeGenerator<'a> {
+    pub fn new(theme: &'a ThemeConfig, highlights: &HashMap<String, HighlightGroup>) -> Self {
+        let normal = highlights.get("Normal");
+        let background = theme
+            .colors
+            .get("editor.background")
+            .or_else(|| normal.and_then(|hl| hl.bg.as_ref()))
+            .and_then(|bg| color::normalize_color(bg, "#000000").ok())
+            .unwrap_or_else(|| {
+                if theme.theme_type == "dark" {
+                    "#1e1e1e"
+                } else {
+                    "#ffffff"
+                }
+                .to_string()
+            });
+        let blend = |value: &str| color::normalize_color(value, &background).ok();
+
+        let mut globals = vec![("background", "background", background.clone())];
+        if let Some(fg) = normal.and_then(|hl| hl.fg.as_deref()).and_then(blend) {
+            globals.push(("foreground", "foreground", fg));
+        }
+        for &(vs_key, sublime_key, tm_key) in GLOBAL_MAPPINGS {
+            let Some(value) = theme.colors.get(vs_key).and_then(|value| blend(value)) else {
+                continue;
+            };
+            match globals.iter_mut().find(|(key, _, _)| *key == sublime_key) {
+                Some(global) => global.2 = value,
+                None => globals.push((sublime_key, tm_key, value)),
+            }
+        }
+
+        let rules = theme
+            .token_colors
+            .iter()
+            .filter_map(|token| {
+                let scope = normalize_selector(&token.scope);
+                if scope.is_empty() {
+                    return None;
+                }
+                let settings = &token.settings;
+                let font_style = settings
+                    .font_style
+                    .as_deref()
+                    .map(|style| style.split_whitespace().collect::<Vec<_>>().join(" "));
+                let rule = Rule {
+                    name: token
+                        .name
+                        .clone()
+                        .filter(|name| !name.is_empty())
+                        .unwrap_or_else(|| scope.clone()),
+                    foreground: settings.foreground.as_deref().and_then(blend),
+                    background: settings.background.as_deref().and_then(blend),
+                    font_style,
+                    scope,
+                };
+                if rule.foreground.is_none()
+                    && rule.background.is_none()
+                    && rule.font_style.is_none()
+                {
+                    return None;
+                }
+                Some(rule)
+            })
+            .collect();
+
+        Self {
+            theme,
+            globals,
+            rules,
+        }
+    }
+
+    /// 生成 `<name>.sublime-color-scheme`
+    pub fn color_scheme(&self) -> Result<String> {
+        let globals: Map<String, Value> = self
+            .globals
+            .iter()
+            .map(|(key, _, value)| (key.to_string(), json!(value)))
+            .collect();
+
+        let rules: Vec<Value> = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let mut entry = Map::new();
+                entry.insert("name".to_string(), json!(rule.name));
+                entry.insert("scope".to_string(), json!(rule.scope));
+                if let Some(fg) = &rule.foreground {
+                    entry.insert("foreground".to_string(), json!(fg));
+                }
+                if let Some(bg) = &rule.background {
+                    entry.insert("background".to_string(), json!(bg));
+                }
+                if let Some(font_style) = &rule.font_style {
+                    entry.insert("font_style".to_string(), json!(font_style));
+                }
+                Value::Object(entry)
+            })
+            .collect();
+
+        let scheme = json!({
+            "name": self.theme.name,
+            "author": "themex",
+            "variables": {},
+            "globals": globals,
+            "rules": rules,
+        });
+
+        let mut content = serde_json::to_string_pretty(&scheme)?;
+        content.push('\n');
+        Ok(content)
+    }
+
+    /// 生成 `<name>.tmTheme` (plist)
+    pub fn tm_theme(&self) -> String {
+        let mut content = String::new();
+        content.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        content.push_str(
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        );
+        content.push_str("<plist version=\"1.0\">\n");
+        content.push_str("<dict>\n");
+        push_entry(&mut content, 1, "name", &self.theme.name);
+        push_entry(&mut content, 1, "author", "themex");
+        content.push_str("\t<key>settings</key>\n");
+        content.push_str("\t<array>\n");
+
+        // 第一项为全局颜色
+        content.push_str("\t\t<dict>\n");
+        content.push_str("\t\t\t<key>settings</key>\n");
+        content.push_str("\t\t\t<dict>\n");
+        for (_, key, value) in &self.globals {
+            push_entry(&mut content, 4, key, value);
+        }
+        content.push_str("\t\t\t</dict>\n");
+        content.push_str("\t\t</dict>\n");
+
+        for rule in &self.rules {
+            content.push_str("\t\t<dict>\n");
+            push_entry(&mut content, 3, "name", &rule.name);
+            push_entry(&mut content, 3, "scope", &rule.scope);
+            content.push_str("\t\t\t<key>settings</key>\n");
+            content.push_str("\t\t\t<dict>\n");
+            if let Some(fg) = &rule.foreground {
+                push_entry(&mut content, 4, "foreground", fg);
+            }
+            if let Some(bg) = &rule.background {
+                push_entry(&mut content, 4, "background", bg);
+            }
+            if let Some(font_style) = &rule.font_style {
+                push_entry(&mut content, 4, "fontStyle", font_style);
+            }
+            content.push_str("\t\t\t</dict>\n");
+            content.push_str("\t\t</dict>\n");
+        }
+
+        content.push_str("\t</array>\n");
+        content.push_str("</dict>\n");
+        content.push_str("</plist>\n");
+        content
+    }
+}
+
+/// 展开多个作用域并拆分逗号分隔的选择器，统一为 `a, b` 形式
+/// 排除语法统一为 `a - b`，括号内的逗号不拆分
+fn normalize_selector(scope: &TokenScope) -> String {
+    let scopes = match scope {
+        TokenScope::Single(scope) => vec![scope.as_str()],
+        TokenScope::Multiple(scopes) => scopes.iter().map(String::as_str).collect(),
+    };
+    scopes
+        .iter()
+        .flat_map(|scope| split_selectors(scope))
+        .map(|selector| {
+            selector
+                .split_whitespace()
+                .map(|part| match part.strip_prefix('-') {
+                    Some(excluded) if !excluded.is_empty() => format!("- {}", excluded),
+                    _ => part.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|selector| !selector.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 按括号外的逗号拆分
+fn split_selectors(scope: &str) -> Vec<&str> {
+    let mut selectors = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in scope.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                selectors.push(&scope[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    selectors.push(&scope[start..]);
+    selectors
+}
+
+fn push_entry(content: &mut String, depth: usize, key: &str, value: &str) {
+    let indent = "\t".repeat(depth);
+    content.push_str(&format!("{}<key>{}</key>\n", indent, escape_xml(key)));
+    content.push_str(&format!(
+        "{}<string>{}</string>\n",
+        indent,
+        escape_xml(value)
+    ));
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
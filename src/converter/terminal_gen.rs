@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::{HighlightGroup, ThemeConfig};
+use crate::utils::first_color;
+
+// ANSI 颜色名，依次对应 color0..7，亮色为 color8..15
+static ANSI_NAMES: &[&str] = &[
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+// VSCode 默认终端颜色 (terminal.ansi* 键, 深色主题默认值, 浅色主题默认值)
+static ANSI_COLORS: &[(&str, &str, &str)] = &[
+    ("terminal.ansiBlack", "#000000", "#000000"),
+    ("terminal.ansiRed", "#cd3131", "#cd3131"),
+    ("terminal.ansiGreen", "#0dbc79", "#00bc00"),
+    ("terminal.ansiYellow", "#e5e510", "#949800"),
+    ("terminal.ansiBlue", "#2472c8", "#0451a5"),
+    ("terminal.ansiMagenta", "#bc3fbc", "#bc05bc"),
+    ("terminal.ansiCyan", "#11a8cd", "#0598bc"),
+    ("terminal.ansiWhite", "#e5e5e5", "#555555"),
+    ("terminal.ansiBrightBlack", "#666666", "#666666"),
+    ("terminal.ansiBrightRed", "#f14c4c", "#cd3131"),
+    ("terminal.ansiBrightGreen", "#23d18b", "#14ce14"),
+    ("terminal.ansiBrightYellow", "#f5f543", "#b5ba00"),
+    ("terminal.ansiBrightBlue", "#3b8eea", "#0451a5"),
+    ("terminal.ansiBrightMagenta", "#d670d6", "#bc05bc"),
+    ("terminal.ansiBrightCyan", "#29b8db", "#0598bc"),
+    ("terminal.ansiBrightWhite", "#e5e5e5", "#a5a5a5"),
+];
+
+/// 终端模拟器配色，取自 `terminal.*`，缺失时退回到编辑器颜色与 VSCode 默认终端颜色
+pub struct TerminalGenerator<'a> {
+    theme: &'a ThemeConfig,
+    // 配色名，WezTerm 通过 `metadata.name` 选择
+    name: String,
+    background: String,
+    foreground: String,
+    cursor: String,
+    cursor_text: String,
+    selection_background: String,
+    selection_foreground: String,
+    // color0..15
+    ansi: Vec<String>,
+}
+
+impl<'a> TerminalGenerator<'a> {
+    pub fn new(theme: &'a ThemeConfig, highlights: &HashMap<String, HighlightGroup>) -> Self {
+        let normal = highlights.get("Normal");
+        let is_dark = theme.theme_type == "dark"
+            || theme
+                .colors
+                .get("editor.background")
+                .is_some_and(|bg| color::is_dark_color(bg));
+
+        let background = first_color(theme, &["terminal.background", "editor.background"], None)
+            .or_else(|| normal.and_then(|hl| hl.bg.clone()))
+            .unwrap_or_else(|| if is_dark { "#1e1e1e" } else { "#ffffff" }.to_string());
+        let background = color::normalize_color(&background, "#000000")
+            .unwrap_or_else(|_| if is_dark { "#1e1e1e" } else { "#ffffff" }.to_string());
+        let foreground = first_color(
+            theme,
+            &["terminal.foreground", "editor.foreground"],
+            Some(&background),
+        )
+        .unwrap_or_else(|| if is_dark { "#cccccc" } else { "#333333" }.to_string());
+        let cursor = first_color(
+            theme,
+            &["terminalCursor.foreground", "editorCursor.foreground"],
+            Some(&background),
+        )
+        .unwrap_or_else(|| foreground.clone());
+        let cursor_text = first_color(
+            theme,
+            &["terminalCursor.background", "editorCursor.background"],
+            Some(&background),
+        )
+        .unwrap_or_else(|| background.clone());
+        let selection_background = first_color(
+            theme,
+            &["terminal.selectionBackground", "editor.selectionBackground"],
+            Some(&background),
+        )
+        .unwrap_or_else(|| if is_dark { "#264f78" } else { "#add6ff" }.to_string());
+        let selection_foreground = first_color(
+            theme,
+            &["terminal.selectionForeground", "editor.selectionForeground"],
+            Some(&background),
+        )
+        .unwrap_or_else(|| foreground.clone());
+
+        let ansi = ANSI_COLORS
+            .iter()
+            .map(|&(key, dark, light)| {
+                first_color(theme, &[key], Some(&background))
+                    .unwrap_or_else(|| if is_dark { dark } else { light }.to_string())
+            })
+            .collect();
+
+        Self {
+            theme,
+            name: theme.name.clone(),
+            background,
+            foreground,
+            cursor,
+            cursor_text,
+            selection_background,
+            selection_foreground,
+            ansi,
+        }
+    }
+
+    /// 使用 `--name` 指定的名称
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Alacritty 配色 (TOML)，通过 `general.import` 引入
+    pub fn alacritty(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# Alacritty colors converted from VSCode Theme\n\n");
+
+        content.push_str("[colors.primary]\n");
+        content.push_str(&format!("background = \"{}\"\n", self.background));
+        content.push_str(&format!("foreground = \"{}\"\n\n", self.foreground));
+
+        content.push_str("[colors.cursor]\n");
+        content.push_str(&format!("text = \"{}\"\n", self.cursor_text));
+        content.push_str(&format!("cursor = \"{}\"\n\n", self.cursor));
+
+        content.push_str("[colors.selection]\n");
+        content.push_str(&format!("text = \"{}\"\n", self.selection_foreground));
+        content.push_str(&format!("background = \"{}\"\n", self.selection_background));
+
+        for (section, colors) in [("normal", &self.ansi[..8]), ("bright", &self.ansi[8..])] {
+            content.push_str(&format!("\n[colors.{}]\n", section));
+            for (name, value) in ANSI_NAMES.iter().zip(colors) {
+                content.push_str(&format!("{} = \"{}\"\n", name, value));
+            }
+        }
+
+        content
+    }
+
+    /// Kitty 配色，通过 `include` 引入
+    pub fn kitty(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# Kitty colors converted from VSCode Theme\n\n");
+
+        content.push_str(&format!("foreground {}\n", self.foreground));
+        content.push_str(&format!("background {}\n", self.background));
+        content.push_str(&format!("cursor {}\n", self.cursor));
+        content.push_str(&format!("cursor_text_color {}\n", self.cursor_text));
+        content.push_str(&format!(
+            "selection_foreground {}\n",
+            self.selection_foreground
+        ));
+        content.push_str(&format!(
+            "selection_background {}\n\n",
+            self.selection_background
+        ));
+
+        for (index, value) in self.ansi.iter().enumerate() {
+            content.push_str(&format!("color{} {}\n", index, value));
+        }
+
+        content
+    }
+
+    /// WezTerm 配色方案 (TOML)，放在 `colors/` 目录下按 `metadata.name` 选择
+    pub fn wezterm(&self) -> String {
+        let quote = |colors: &[String]| {
+            colors
+                .iter()
+                .map(|value| format!("\"{}\"", value))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# WezTerm color scheme converted from VSCode Theme\n\n");
+
+        content.push_str("[colors]\n");
+        content.push_str(&format!("foreground = \"{}\"\n", self.foreground));
+        content.push_str(&format!("background = \"{}\"\n", self.background));
+        content.push_str(&format!("cursor_bg = \"{}\"\n", self.cursor));
+        content.push_str(&format!("cursor_fg = \"{}\"\n", self.cursor_text));
+        content.push_str(&format!("cursor_border = \"{}\"\n", self.cursor));
+        content.push_str(&format!(
+            "selection_fg = \"{}\"\n",
+            self.selection_foreground
+        ));
+        content.push_str(&format!(
+            "selection_bg = \"{}\"\n",
+            self.selection_background
+        ));
+        content.push_str(&format!("ansi = [{}]\n", quote(&self.ansi[..8])));
+        content.push_str(&format!("brights = [{}]\n\n", quote(&self.ansi[8..])));
+
+        content.push_str("[metadata]\n");
+        content.push_str(&format!("name = \"{}\"\n", self.name));
+
+        content
+    }
+
+    /// Ghostty 主题，放在配置目录的 `themes/` 下通过 `theme = <name>` 选择
+    pub fn ghostty(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# Ghostty theme converted from VSCode Theme\n\n");
+
+        for (index, value) in self.ansi.iter().enumerate() {
+            content.push_str(&format!("palette = {}={}\n", index, value));
+        }
+        content.push_str(&format!("background = {}\n", self.background));
+        content.push_str(&format!("foreground = {}\n", self.foreground));
+        content.push_str(&format!("cursor-color = {}\n", self.cursor));
+        content.push_str(&format!("cursor-text = {}\n", self.cursor_text));
+        content.push_str(&format!(
+            "selection-background = {}\n",
+            self.selection_background
+        ));
+        content.push_str(&format!(
+            "selection-foreground = {}\n",
+            self.selection_foreground
+        ));
+
+        content
+    }
+
+    /// foot 配色 (INI)，颜色不带 `#`
+    pub fn foot(&self) -> String {
+        let hex = |value: &str| value.trim_start_matches('#').to_string();
+
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# foot colors converted from VSCode Theme\n\n");
+
+        content.push_str("[cursor]\n");
+        content.push_str(&format!(
+            "color={} {}\n\n",
+            hex(&self.cursor_text),
+            hex(&self.cursor)
+        ));
+
+        content.push_str("[colors]\n");
+        content.push_str(&format!("foreground={}\n", hex(&self.foreground)));
+        content.push_str(&format!("background={}\n", hex(&self.background)));
+        for (index, value) in self.ansi[..8].iter().enumerate() {
+            content.push_str(&format!("regular{}={}\n", index, hex(value)));
+        }
+        for (index, value) in self.ansi[8..].iter().enumerate() {
+            content.push_str(&format!("bright{}={}\n", index, hex(value)));
+        }
+        content.push_str(&format!(
+            "selection-foreground={}\n",
+            hex(&self.selection_foreground)
+        ));
+        content.push_str(&format!(
+            "selection-background={}\n",
+            hex(&self.selection_background)
+        ));
+
+        content
+    }
+}
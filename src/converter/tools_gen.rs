@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::{HighlightGroup, ThemeConfig};
+use crate::utils::first_color;
+
+/// tmux、fzf 与 lazygit 配置片段，颜色取自状态栏、选中项、列表高亮与边框
+pub struct ToolsGenerator<'a> {
+    theme: &'a ThemeConfig,
+    background: String,
+    foreground: String,
+    status_background: String,
+    status_foreground: String,
+    // 当前窗口、活动边框等强调色
+    accent: String,
+    selection_background: String,
+    selection_foreground: String,
+    // 列表中匹配的字符
+    highlight: String,
+    border: String,
+    // 次要文字
+    muted: String,
+    error: String,
+}
+
+impl<'a> ToolsGenerator<'a> {
+    pub fn new(theme: &'a ThemeConfig, highlights: &HashMap<String, HighlightGroup>) -> Self {
+        let normal = highlights.get("Normal");
+        let is_dark = theme.theme_type == "dark"
+            || theme
+                .colors
+                .get("editor.background")
+                .is_some_and(|bg| color::is_dark_color(bg));
+
+        let background = first_color(theme, &["editor.background"], Some("#000000"))
+            .or_else(|| {
+                normal
+                    .and_then(|hl| hl.bg.as_deref())
+                    .and_then(|bg| color::normalize_color(bg, "#000000").ok())
+            })
+            .unwrap_or_else(|| if is_dark { "#1e1e1e" } else { "#ffffff" }.to_string());
+        let bg = Some(background.as_str());
+        let foreground = first_color(theme, &["editor.foreground"], bg)
+            .or_else(|| {
+                normal
+                    .and_then(|hl| hl.fg.as_deref())
+                    .and_then(|fg| color::normalize_color(fg, &background).ok())
+            })
+            .unwrap_or_else(|| if is_dark { "#cccccc" } else { "#333333" }.to_string());
+
+        let status_background =
+            first_color(theme, &["statusBar.background"], bg).unwrap_or_else(|| background.clone());
+        let status_foreground =
+            first_color(theme, &["statusBar.foreground"], bg).unwrap_or_else(|| foreground.clone());
+        let accent = first_color(
+            theme,
+            &[
+                "focusBorder",
+                "activityBarBadge.background",
+                "editorCursor.foreground",
+            ],
+            bg,
+        )
+        .unwrap_or_else(|| foreground.clone());
+        let selection_background = first_color(
+            theme,
+            &[
+                "list.activeSelectionBackground",
+                "editor.selectionBackground",
+            ],
+            bg,
+        )
+        .unwrap_or_else(|| if is_dark { "#264f78" } else { "#add6ff" }.to_string());
+        let selection_foreground = first_color(theme, &["list.activeSelectionForeground"], bg)
+            .unwrap_or_else(|| foreground.clone());
+        let highlight =
+            first_color(theme, &["list.highlightForeground"], bg).unwrap_or_else(|| accent.clone());
+        let border = first_color(
+            theme,
+            &["panel.border", "editorGroup.border", "contrastBorder"],
+            bg,
+        )
+        .unwrap_or_else(|| status_background.clone());
+        let muted = first_color(
+            theme,
+            &["descriptionForeground", "editorLineNumber.foreground"],
+            bg,
+        )
+        .unwrap_or_else(|| foreground.clone());
+        let error = first_color(theme, &["editorError.foreground", "errorForeground"], bg)
+            .unwrap_or_else(|| "#f14c4c".to_string());
+
+        Self {
+            theme,
+            background,
+            foreground,
+            status_background,
+            status_foreground,
+            accent,
+            selection_background,
+            selection_foreground,
+            highlight,
+            border,
+            muted,
+            error,
+        }
+    }
+
+    /// tmux 状态栏与窗格边框，通过 `source-file` 引入
+    pub fn tmux(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# tmux colors converted from VSCode Theme\n\n");
+
+        let status = format!(
+            "fg={},bg={}",
+            self.status_foreground, self.status_background
+        );
+        content.push_str(&format!("set -g status-style \"{}\"\n", status));
+        content.push_str(&format!(
+            "set -g window-status-style \"fg={},bg={}\"\n",
+            self.muted, self.status_background
+        ));
+        content.push_str(&format!(
+            "set -g window-status-current-style \"fg={},bg={},bold\"\n",
+            self.accent, self.status_background
+        ));
+        content.push_str(&format!(
+            "set -g pane-border-style \"fg={}\"\n",
+            self.border
+        ));
+        content.push_str(&format!(
+            "set -g pane-active-border-style \"fg={}\"\n",
+            self.accent
+        ));
+        let selection = format!(
+            "fg={},bg={}",
+            self.selection_foreground, self.selection_background
+        );
+        content.push_str(&format!("set -g message-style \"{}\"\n", selection));
+        content.push_str(&format!("set -g message-command-style \"{}\"\n", selection));
+        content.push_str(&format!("set -g mode-style \"{}\"\n", selection));
+        content.push_str(&format!(
+            "set -g display-panes-active-colour \"{}\"\n",
+            self.accent
+        ));
+        content.push_str(&format!("set -g display-panes-colour \"{}\"\n", self.muted));
+        content.push_str(&format!("set -g clock-mode-colour \"{}\"\n", self.accent));
+
+        content
+    }
+
+    /// fzf `--color=` 参数
+    pub fn fzf_colors(&self) -> String {
+        let colors = [
+            ("fg", &self.foreground),
+            ("bg", &self.background),
+            ("hl", &self.highlight),
+            ("fg+", &self.selection_foreground),
+            ("bg+", &self.selection_background),
+            ("hl+", &self.highlight),
+            ("info", &self.muted),
+            ("border", &self.border),
+            ("gutter", &self.background),
+            ("header", &self.muted),
+            ("prompt", &self.accent),
+            ("pointer", &self.accent),
+            ("marker", &self.accent),
+            ("spinner", &self.accent),
+        ];
+        let colors = colors
+            .iter()
+            .map(|(name, value)| format!("{}:{}", name, value))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("--color={}", colors)
+    }
+
+    /// 追加到 `FZF_DEFAULT_OPTS` 的 shell 片段
+    pub fn fzf(&self) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# fzf colors converted from VSCode Theme\n\n");
+        content.push_str(&format!(
+            "export FZF_DEFAULT_OPTS=\"$FZF_DEFAULT_OPTS {}\"\n",
+            self.fzf_colors()
+        ));
+        content
+    }
+
+    /// lazygit `gui.theme` (YAML)
+    pub fn lazygit(&self) -> String {
+        let theme = [
+            ("activeBorderColor", &self.accent, true),
+            ("inactiveBorderColor", &self.border, false),
+            ("searchingActiveBorderColor", &self.highlight, true),
+            ("optionsTextColor", &self.muted, false),
+            ("selectedLineBgColor", &self.selection_background, false),
+            (
+                "inactiveViewSelectedLineBgColor",
+                &self.status_background,
+                false,
+            ),
+            ("cherryPickedCommitFgColor", &self.accent, false),
+            (
+                "cherryPickedCommitBgColor",
+                &self.selection_background,
+                false,
+            ),
+            ("markedBaseCommitFgColor", &self.highlight, false),
+            ("markedBaseCommitBgColor", &self.selection_background, false),
+            ("unstagedChangesColor", &self.error, false),
+            ("defaultFgColor", &self.foreground, false),
+        ];
+
+        let mut content = String::new();
+        content.push_str(&format!("# Name: {}\n", self.theme.name));
+        content.push_str("# lazygit theme converted from VSCode Theme\n\n");
+        content.push_str("gui:\n");
+        content.push_str("  theme:\n");
+        for (key, value, bold) in theme {
+            content.push_str(&format!("    {}:\n", key));
+            content.push_str(&format!("      - \"{}\"\n", value));
+            if bold {
+                content.push_str("      - bold\n");
+            }
+        }
+        content
+    }
+}
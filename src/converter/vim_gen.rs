@@ -1,17 +1,43 @@
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use super::color;
+use super::{color, mapping};
 use crate::types::{HighlightGroup, ThemeConfig};
 
 pub struct VimGenerator<'a> {
     theme: &'a ThemeConfig,
     highlights: &'a HashMap<String, HighlightGroup>,
+    integrations: &'a [(String, Vec<String>)],
+    colors_name: String,
+    // 透明背景，Vim 没有运行时选项，生成时直接清除
+    transparent: bool,
 }
 
 impl<'a> VimGenerator<'a> {
-    pub fn new(theme: &'a ThemeConfig, highlights: &'a HashMap<String, HighlightGroup>) -> Self {
-        Self { theme, highlights }
+    pub fn new(
+        theme: &'a ThemeConfig,
+        highlights: &'a HashMap<String, HighlightGroup>,
+        integrations: &'a [(String, Vec<String>)],
+    ) -> Self {
+        Self {
+            theme,
+            highlights,
+            integrations,
+            colors_name: sanitize_name(&theme.name),
+            transparent: false,
+        }
+    }
+
+    /// 指定 `g:colors_name`，需与 `colors/` 下的文件名一致
+    pub fn with_colors_name(mut self, name: &str) -> Self {
+        self.colors_name = name.to_string();
+        self
+    }
+
+    /// `mapping::TRANSPARENT_GROUPS` 中的高亮组写成 `guibg=NONE`
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
     }
 
     pub fn generate(&self) -> Result<String> {
@@ -32,7 +58,7 @@ impl<'a> VimGenerator<'a> {
         content.push_str("  syntax reset\n");
         content.push_str("endif\n\n");
 
-        let theme_name = sanitize_name(&self.theme.name);
+        let theme_name = &self.colors_name;
         content.push_str(&format!("let g:colors_name = \"{}\"\n\n", theme_name));
 
         content.push_str("\" Highlight groups\n");
@@ -42,13 +68,36 @@ impl<'a> VimGenerator<'a> {
             content.push('\n');
         }
 
+        let integration_groups: HashSet<&String> = self
+            .integrations
+            .iter()
+            .flat_map(|(_, groups)| groups)
+            .collect();
         for (group, highlight) in self.highlights.iter() {
-            if group != "Normal" {
+            if group != "Normal" && !integration_groups.contains(group) {
                 content.push_str(&self.format_highlight_group(group, highlight));
                 content.push('\n');
             }
         }
 
+        // 插件集成，可通过 g:<主题名>_<集成名> = 0 关闭
+        for (name, groups) in self.integrations {
+            content.push_str(&format!("\n\" Integration: {}\n", name));
+            content.push_str(&format!(
+                "if get(g:, '{}_{}', 1)\n",
+                theme_name,
+                sanitize_name(name)
+            ));
+            for group in groups {
+                if let Some(highlight) = self.highlights.get(group) {
+                    content.push_str("  ");
+                    content.push_str(&self.format_highlight_group(group, highlight));
+                    content.push('\n');
+                }
+            }
+            content.push_str("endif\n");
+        }
+
         content.push_str("\n\" Default links\n");
         for default_link in DEFAULT_LINKS {
             if !self.highlights.contains_key(default_link.0) {
@@ -64,24 +113,28 @@ impl<'a> VimGenerator<'a> {
             return format!("hi link {} {}", group, link);
         }
 
+        // 半透明颜色与编辑器背景混合，与 Lua 输出保持一致
         let mut parts = Vec::new();
+        let editor_bg = self.theme.colors.get("editor.background");
 
-        if let Some(fg) = &highlight.fg {
-            if let Ok(normalized_fg) = color::normalize_color(fg, fg) {
-                parts.push(format!("guifg={}", normalized_fg));
-            }
+        if let Some(fg) = &highlight.fg
+            && let Ok(normalized_fg) = color::normalize_color(fg, editor_bg.unwrap_or(fg))
+        {
+            parts.push(format!("guifg={}", normalized_fg));
         }
 
-        if let Some(bg) = &highlight.bg {
-            if let Ok(normalized_bg) = color::normalize_color(bg, bg) {
-                parts.push(format!("guibg={}", normalized_bg));
-            }
+        if self.transparent && mapping::TRANSPARENT_GROUPS.contains(&group) {
+            parts.push("guibg=NONE".to_string());
+        } else if let Some(bg) = &highlight.bg
+            && let Ok(normalized_bg) = color::normalize_color(bg, editor_bg.unwrap_or(bg))
+        {
+            parts.push(format!("guibg={}", normalized_bg));
         }
 
-        if let Some(sp) = &highlight.sp {
-            if let Ok(normalized_sp) = color::normalize_color(sp, sp) {
-                parts.push(format!("guisp={}", normalized_sp));
-            }
+        if let Some(sp) = &highlight.sp
+            && let Ok(normalized_sp) = color::normalize_color(sp, editor_bg.unwrap_or(sp))
+        {
+            parts.push(format!("guisp={}", normalized_sp));
         }
 
         let mut style_parts = Vec::new();
@@ -0,0 +1,258 @@
+use anyhow::Result;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+use super::color;
+use crate::types::{HighlightGroup, ThemeConfig};
+
+// VSCode 界面颜色 -> Zed style 键，同一键后面的条目覆盖前面的
+// Zed 支持 #RRGGBBAA，界面颜色保留原始透明度
+static ZED_STYLE_MAPPINGS: &[(&str, &str)] = &[
+    // 整体
+    ("editor.background", "background"),
+    ("editor.foreground", "text"),
+    ("descriptionForeground", "text.muted"),
+    ("disabledForeground", "text.disabled"),
+    ("textLink.foreground", "text.accent"),
+    ("editorGroup.border", "border"),
+    ("panel.border", "border.variant"),
+    ("focusBorder", "border.focused"),
+    ("sideBar.background", "surface.background"),
+    ("editorWidget.background", "elevated_surface.background"),
+    ("list.hoverBackground", "element.hover"),
+    ("list.activeSelectionBackground", "element.selected"),
+    ("list.hoverBackground", "ghost_element.hover"),
+    ("list.inactiveSelectionBackground", "ghost_element.selected"),
+    // 编辑器
+    ("editor.background", "editor.background"),
+    ("editor.foreground", "editor.foreground"),
+    ("editor.background", "editor.gutter.background"),
+    ("editorGutter.background", "editor.gutter.background"),
+    ("editorLineNumber.foreground", "editor.line_number"),
+    (
+        "editorLineNumber.activeForeground",
+        "editor.active_line_number",
+    ),
+    (
+        "editor.lineHighlightBackground",
+        "editor.active_line.background",
+    ),
+    ("editorIndentGuide.background", "editor.indent_guide"),
+    (
+        "editorIndentGuide.activeBackground",
+        "editor.indent_guide_active",
+    ),
+    ("editorWhitespace.foreground", "editor.invisible"),
+    (
+        "editorBracketMatch.background",
+        "editor.document_highlight.bracket_background",
+    ),
+    (
+        "editor.wordHighlightBackground",
+        "editor.document_highlight.read_background",
+    ),
+    (
+        "editor.wordHighlightStrongBackground",
+        "editor.document_highlight.write_background",
+    ),
+    (
+        "editor.findMatchHighlightBackground",
+        "search.match_background",
+    ),
+    ("scrollbarSlider.background", "scrollbar.thumb.background"),
+    (
+        "scrollbarSlider.hoverBackground",
+        "scrollbar.thumb.hover_background",
+    ),
+    // 状态栏、标题栏、标签页与面板
+    ("statusBar.background", "status_bar.background"),
+    ("titleBar.activeBackground", "title_bar.background"),
+    (
+        "titleBar.inactiveBackground",
+        "title_bar.inactive_background",
+    ),
+    ("editorGroupHeader.tabsBackground", "tab_bar.background"),
+    ("tab.activeBackground", "tab.active_background"),
+    ("tab.inactiveBackground", "tab.inactive_background"),
+    ("sideBar.background", "panel.background"),
+    // 诊断与版本控制
+    ("editorError.foreground", "error"),
+    ("editorWarning.foreground", "warning"),
+    ("editorInfo.foreground", "info"),
+    ("editorHint.foreground", "hint"),
+    ("gitDecoration.addedResourceForeground", "created"),
+    ("gitDecoration.deletedResourceForeground", "deleted"),
+    ("gitDecoration.modifiedResourceForeground", "modified"),
+    ("gitDecoration.ignoredResourceForeground", "ignored"),
+    ("gitDecoration.conflictingResourceForeground", "conflict"),
+    ("editorGutter.addedBackground", "version_control.added"),
+    (
+        "editorGutter.modifiedBackground",
+        "version_control.modified",
+    ),
+    ("editorGutter.deletedBackground", "version_control.deleted"),
+    // 终端
+    ("terminal.background", "terminal.background"),
+    ("terminal.foreground", "terminal.foreground"),
+    ("terminal.ansiBlack", "terminal.ansi.black"),
+    ("terminal.ansiRed", "terminal.ansi.red"),
+    ("terminal.ansiGreen", "terminal.ansi.green"),
+    ("terminal.ansiYellow", "terminal.ansi.yellow"),
+    ("terminal.ansiBlue", "terminal.ansi.blue"),
+    ("terminal.ansiMagenta", "terminal.ansi.magenta"),
+    ("terminal.ansiCyan", "terminal.ansi.cyan"),
+    ("terminal.ansiWhite", "terminal.ansi.white"),
+    ("terminal.ansiBrightBlack", "terminal.ansi.bright_black"),
+    ("terminal.ansiBrightRed", "terminal.ansi.bright_red"),
+    ("terminal.ansiBrightGreen", "terminal.ansi.bright_green"),
+    ("terminal.ansiBrightYellow", "terminal.ansi.bright_yellow"),
+    ("terminal.ansiBrightBlue", "terminal.ansi.bright_blue"),
+    ("terminal.ansiBrightMagenta", "terminal.ansi.bright_magenta"),
+    ("terminal.ansiBrightCyan", "terminal.ansi.bright_cyan"),
+    ("terminal.ansiBrightWhite", "terminal.ansi.bright_white"),
+];
+
+// Zed syntax 键 -> 高亮组，同一键后面的条目覆盖前面的
+static ZED_SYNTAX_MAPPINGS: &[(&str, &str)] = &[
+    ("comment", "Comment"),
+    ("comment.doc", "SpecialComment"),
+    ("keyword", "Keyword"),
+    ("function", "Function"),
+    ("type", "Type"),
+    ("constructor", "Type"),
+    ("constructor", "Structure"),
+    ("constant", "Constant"),
+    ("boolean", "Constant"),
+    ("number", "Number"),
+    ("string", "String"),
+    ("string.escape", "SpecialChar"),
+    ("string.special", "Special"),
+    ("variable", "Identifier"),
+    ("property", "Identifier"),
+    ("operator", "Operator"),
+    ("punctuation", "Delimiter"),
+    ("punctuation.bracket", "Delimiter"),
+    ("punctuation.delimiter", "Delimiter"),
+    ("preproc", "Macro"),
+    ("tag", "Tag"),
+    ("title", "Title"),
+    ("emphasis", "Italic"),
+    ("emphasis.strong", "Bold"),
+    ("link_uri", "Underlined"),
+];
+
+pub struct ZedGenerator<'a> {
+    theme: &'a ThemeConfig,
+    highlights: &'a HashMap<String, HighlightGroup>,
+    // 主题族与主题名，显示在 Zed 的主题选择器中
+    name: String,
+}
+
+impl<'a> ZedGenerator<'a> {
+    pub fn new(
+        theme: &'a ThemeConfig,
+        highlights: &'a HashMap<String, HighlightGroup>,
+        name: &str,
+    ) -> Self {
+        Self {
+            theme,
+            highlights,
+            name: name.to_string(),
+        }
+    }
+
+    /// 生成 `themes/<name>.json` 主题族文件
+    pub fn generate(&self) -> Result<String> {
+        let mut style = Map::new();
+        for &(vs_key, zed_key) in ZED_STYLE_MAPPINGS {
+            if let Some(value) = self.color(vs_key) {
+                style.insert(zed_key.to_string(), Value::String(value));
+            }
+        }
+
+        if let Some(cursor) = self.color("editorCursor.foreground") {
+            let mut player = Map::new();
+            player.insert("cursor".to_string(), json!(cursor));
+            player.insert("background".to_string(), json!(cursor));
+            if let Some(selection) = self.color("editor.selectionBackground") {
+                player.insert("selection".to_string(), json!(selection));
+            }
+            style.insert("players".to_string(), json!([player]));
+        }
+
+        style.insert("syntax".to_string(), Value::Object(self.syntax()));
+
+        let appearance = if self.is_dark_theme() {
+            "dark"
+        } else {
+            "light"
+        };
+        let family = json!({
+            "$schema": "https://zed.dev/schema/themes/v0.2.0.json",
+            "name": self.name,
+            "author": "themex",
+            "themes": [{
+                "name": self.name,
+                "appearance": appearance,
+                "style": style,
+            }],
+        });
+
+        let mut content = serde_json::to_string_pretty(&family)?;
+        content.push('\n');
+        Ok(content)
+    }
+
+    /// 界面颜色，`#RGB` 与 `#RGBA` 展开为 Zed 可解析的 `#rrggbb[aa]`
+    fn color(&self, key: &str) -> Option<String> {
+        let value = self.theme.colors.get(key)?;
+        match color::expand_hex(value) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                log::debug!("skip zed color {}: {}", key, err);
+                None
+            }
+        }
+    }
+
+    /// 语法高亮，颜色与编辑器背景混合，保留粗体与斜体
+    fn syntax(&self) -> Map<String, Value> {
+        let editor_bg = self.theme.colors.get("editor.background");
+
+        let mut syntax = Map::new();
+        for &(zed_key, group) in ZED_SYNTAX_MAPPINGS {
+            let Some(highlight) = self.highlights.get(group) else {
+                continue;
+            };
+            let Some(fg) = &highlight.fg else {
+                continue;
+            };
+            let Ok(fg) = color::normalize_color(fg, editor_bg.unwrap_or(fg)) else {
+                continue;
+            };
+
+            let mut style = Map::new();
+            style.insert("color".to_string(), json!(fg));
+            if highlight.italic.unwrap_or(false) {
+                style.insert("font_style".to_string(), json!("italic"));
+            }
+            if highlight.bold.unwrap_or(false) {
+                style.insert("font_weight".to_string(), json!(700));
+            }
+            syntax.insert(zed_key.to_string(), Value::Object(style));
+        }
+        syntax
+    }
+
+    fn is_dark_theme(&self) -> bool {
+        if self.theme.theme_type == "dark" {
+            return true;
+        }
+
+        if let Some(bg) = self.theme.colors.get("editor.background") {
+            return color::is_dark_color(bg);
+        }
+
+        false
+    }
+}
@@ -26,6 +26,18 @@ struct Cli {
     #[arg(long, default_value_t = true)]
     lsp: bool,
 
+    /// Plugin integrations to generate, comma separated (default: all)
+    #[arg(long, value_delimiter = ',')]
+    plugins: Option<Vec<String>>,
+
+    /// Also write colors/<name>.vim in plugin output
+    #[arg(long)]
+    vim_fallback: bool,
+
+    /// Remove backgrounds from Normal, floats, side bars and gutters
+    #[arg(long)]
+    transparent: bool,
+
     #[arg(short, long)]
     verbose: bool,
 }
@@ -48,6 +60,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         custom_name: args.name,
         include_treesitter: args.treesitter,
         include_lsp: args.lsp,
+        plugins: args.plugins,
+        vim_fallback: args.vim_fallback,
+        transparent: args.transparent,
     };
     let mut converter = ThemeConverter::new(theme, options);
     converter.convert()?;
@@ -76,7 +91,7 @@ mod tests {
         assert_eq!(theme.name, "Catppuccin Latte");
         assert_eq!(theme.theme_type, "light");
         assert_eq!(theme.colors.len(), 529);
-        assert_eq!(theme.semantic_highlighting, true);
+        assert!(theme.semantic_highlighting);
         assert_eq!(
             theme.semantic_token_colors.as_ref().unwrap().tokens.len(),
             30
@@ -88,10 +103,13 @@ mod tests {
             custom_name: Some("test_output".to_string()),
             include_treesitter: true,
             include_lsp: true,
+            plugins: None,
+            vim_fallback: false,
+            transparent: false,
         };
 
         let mut converter = ThemeConverter::new(theme, options);
-        let result = converter.convert()?;
+        converter.convert()?;
         let output_dir = PathBuf::from(".");
         converter.save_to_files(&output_dir)?;
 
@@ -107,6 +125,9 @@ mod tests {
             output_path: Some(PathBuf::from("./test_output")),
             treesitter: true,
             lsp: false,
+            plugins: Some(vec!["telescope".to_string()]),
+            vim_fallback: false,
+            transparent: false,
             verbose: true,
         };
 
@@ -115,12 +136,16 @@ mod tests {
             custom_name: args.name,
             include_treesitter: args.treesitter,
             include_lsp: args.lsp,
+            plugins: args.plugins,
+            vim_fallback: args.vim_fallback,
+            transparent: args.transparent,
         };
 
         assert_eq!(options.output_format, OutputFormat::Both);
         assert_eq!(options.custom_name, Some("custom_name".to_string()));
-        assert_eq!(options.include_treesitter, true);
-        assert_eq!(options.include_lsp, false);
+        assert!(options.include_treesitter);
+        assert!(!options.include_lsp);
+        assert_eq!(options.plugins, Some(vec!["telescope".to_string()]));
         assert_eq!(args.output_path, Some(PathBuf::from("./test_output")));
 
         Ok(())
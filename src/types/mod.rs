@@ -1,7 +1,7 @@
 pub mod semantic;
 pub mod token;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
@@ -20,6 +20,25 @@ pub struct ThemeConfig {
     pub token_colors: Vec<token::TokenColor>,
 }
 
+impl ThemeConfig {
+    /// 主题中出现的所有颜色，包括 `colors`、`tokenColors` 与 `semanticTokenColors`
+    pub fn get_unique_colors(&self) -> HashSet<String> {
+        let mut unique_colors: HashSet<String> = self.colors.values().cloned().collect();
+        for token in self.token_colors.iter() {
+            if let Some(foreground) = token.settings.foreground.clone() {
+                unique_colors.insert(foreground);
+            }
+            if let Some(background) = token.settings.background.clone() {
+                unique_colors.insert(background);
+            }
+        }
+        if let Some(semantic_token_colors) = &self.semantic_token_colors {
+            unique_colors.extend(semantic_token_colors.get_unique_colors());
+        }
+        unique_colors
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct HighlightGroup {
     pub fg: Option<String>,
@@ -33,6 +52,21 @@ pub struct HighlightGroup {
     pub link: Option<String>,
 }
 
+impl HighlightGroup {
+    /// 解析 VSCode `fontStyle`，如 `"bold italic"`
+    pub fn apply_font_style(&mut self, font_style: &str) {
+        for style in font_style.split_whitespace() {
+            match style {
+                "bold" => self.bold = Some(true),
+                "italic" => self.italic = Some(true),
+                "underline" => self.underline = Some(true),
+                "strikethrough" => self.strikethrough = Some(true),
+                _ => log::debug!("unknown font style: {}", style),
+            }
+        }
+    }
+}
+
 impl fmt::Debug for ThemeConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let colors_summary = format!("[{} entries]", self.colors.len());
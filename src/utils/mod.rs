@@ -1,18 +1,92 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
 use std::fs;
 use std::path::Path;
 
+use crate::converter::color;
 use crate::types::ThemeConfig;
 
-/// 读取并解析主题文件
+// include 最多嵌套的层数
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// 读取并解析主题文件，`include` 引用的父主题会先合并进来
 pub fn read_and_parse_theme(path: &Path) -> Result<ThemeConfig> {
+    let theme = read_theme_value(path, 0)?;
+
+    let theme: ThemeConfig = serde_json::from_value(theme)
+        .with_context(|| format!("Failed to parse theme JSON from: {}", path.display()))?;
+
+    Ok(theme)
+}
+
+/// 读取主题 JSON 并展开 `include`，路径相对于当前主题文件
+fn read_theme_value(path: &Path, depth: usize) -> Result<Value> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read theme file: {}", path.display()))?;
 
-    let theme: ThemeConfig = serde_json::from_str(&content)
+    let mut theme: Value = serde_json::from_str(&content)
         .with_context(|| format!("Failed to parse theme JSON from: {}", path.display()))?;
+    let Some(object) = theme.as_object_mut() else {
+        bail!("Theme file is not a JSON object: {}", path.display());
+    };
+    if object.get("tokenColors").is_some_and(Value::is_string) {
+        bail!(
+            "tokenColors referencing a tmTheme file is not supported: {}",
+            path.display()
+        );
+    }
 
-    Ok(theme)
+    let Some(include) = object.remove("include") else {
+        return Ok(theme);
+    };
+    let Some(include) = include.as_str() else {
+        bail!("`include` must be a file path in: {}", path.display());
+    };
+    if depth >= MAX_INCLUDE_DEPTH {
+        bail!("Too many nested includes in theme file: {}", path.display());
+    }
+
+    let parent_path = path.parent().unwrap_or(Path::new(".")).join(include);
+    log::debug!("include theme {:?}", parent_path);
+    let parent = read_theme_value(&parent_path, depth + 1).with_context(|| {
+        format!(
+            "Failed to resolve include \"{}\" from: {}",
+            include,
+            path.display()
+        )
+    })?;
+
+    Ok(merge_theme(parent, theme))
+}
+
+/// 合并父主题，`colors` 与 `semanticTokenColors` 按键覆盖，`tokenColors` 追加在父主题之后
+fn merge_theme(parent: Value, theme: Value) -> Value {
+    let (Value::Object(mut merged), Value::Object(theme)) = (parent, theme) else {
+        unreachable!("theme files are checked to be JSON objects");
+    };
+    for (key, value) in theme {
+        match (key.as_str(), merged.get_mut(&key), value) {
+            ("colors" | "semanticTokenColors", Some(Value::Object(base)), Value::Object(value)) => {
+                base.extend(value)
+            }
+            ("tokenColors", Some(Value::Array(base)), Value::Array(value)) => base.extend(value),
+            (_, _, value) => {
+                merged.insert(key, value);
+            }
+        }
+    }
+    Value::Object(merged)
+}
+
+/// 按顺序取主题中第一个存在的颜色，`bg` 不为空时与之混合去掉透明度
+pub fn first_color(theme: &ThemeConfig, keys: &[&str], bg: Option<&str>) -> Option<String> {
+    keys.iter().find_map(|key| {
+        let value = theme.colors.get(*key)?;
+        match bg {
+            Some(bg) => color::normalize_color(value, bg).ok(),
+            None => Some(value.clone()),
+        }
+    })
 }
 
 /// 确保目录存在
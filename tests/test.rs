@@ -1,8 +1,9 @@
-use themex::converter::{ConversionOptions, OutputFormat, ThemeConverter};
+use themex::converter::{ConversionOptions, OutputFormat, ThemeConverter, color, plugins};
+use themex::types::HighlightGroup;
 use themex::utils;
 
 use anyhow::Result;
-use std::str::FromStr;
+use std::collections::HashMap;
 use std::{env, path::PathBuf};
 
 fn get_test_file_path(filename: &str) -> PathBuf {
@@ -12,6 +13,57 @@ fn get_test_file_path(filename: &str) -> PathBuf {
     path
 }
 
+/// 测试用转换选项，启用 treesitter 与 LSP，其余为默认值
+fn test_options(output_format: OutputFormat) -> ConversionOptions {
+    ConversionOptions {
+        output_format,
+        custom_name: None,
+        include_treesitter: true,
+        include_lsp: true,
+        plugins: None,
+        vim_fallback: false,
+        transparent: false,
+    }
+}
+
+fn convert_test_theme(filename: &str) -> Result<HashMap<String, HighlightGroup>> {
+    let theme = utils::read_and_parse_theme(&get_test_file_path(filename))?;
+    let options = test_options(OutputFormat::Lua);
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    Ok(converter.get_highlights())
+}
+
+/// 高亮表项的键为 Lua 标识符或 `["..."]`
+fn assert_valid_lua_keys(content: &str) {
+    for line in content.lines() {
+        let line = line.trim_start();
+        let Some((key, value)) = line.split_once(" = ") else {
+            continue;
+        };
+        if !value.starts_with("{ ") || !line.ends_with("},") {
+            continue;
+        }
+        let valid = match key
+            .strip_prefix("[\"")
+            .and_then(|key| key.strip_suffix("\"]"))
+        {
+            Some(name) => !name.contains(['"', '\\']),
+            None => {
+                key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }
+        };
+        assert!(valid, "invalid Lua key in: {}", line);
+    }
+    assert_eq!(
+        content.matches('{').count(),
+        content.matches('}').count(),
+        "unbalanced braces"
+    );
+}
+
 #[test]
 fn test_theme_loading() -> Result<()> {
     let theme_path = get_test_file_path("test_catppuccin.json");
@@ -21,14 +73,12 @@ fn test_theme_loading() -> Result<()> {
     assert_eq!(theme.theme_type, "dark");
 
     let options = ConversionOptions {
-        output_format: OutputFormat::Lua,
         custom_name: Some("test_output".to_string()),
-        include_treesitter: true,
-        include_lsp: true,
+        ..test_options(OutputFormat::Lua)
     };
 
     let mut converter = ThemeConverter::new(theme, options);
-    let result = converter.convert()?;
+    converter.convert()?;
     let highlight = converter.get_highlights();
 
     for (group, highlight) in highlight.iter() {
@@ -59,3 +109,1004 @@ fn test_theme_loading() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_telescope_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    let normal = highlight.get("TelescopeNormal").unwrap();
+    assert_eq!(normal.fg, Some("#B0B7C3".to_string()));
+    assert_eq!(normal.bg, Some("#21252B".to_string()));
+
+    let prompt = highlight.get("TelescopePromptNormal").unwrap();
+    assert_eq!(prompt.bg, Some("#282c34".to_string()));
+
+    assert_eq!(
+        highlight.get("TelescopeSelection").unwrap().bg,
+        Some("#598DEF3F".to_string())
+    );
+    assert_eq!(
+        highlight.get("TelescopeMatching").unwrap().fg,
+        Some("#5889EA".to_string())
+    );
+    assert_eq!(
+        highlight.get("TelescopeResultsTitle").unwrap().fg,
+        Some("#4480F4".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_plugin_selection() -> Result<()> {
+    let theme_path = get_test_file_path("test_eva.json");
+
+    let options = ConversionOptions {
+        plugins: Some(vec![]),
+        ..test_options(OutputFormat::Both)
+    };
+    let mut converter = ThemeConverter::new(utils::read_and_parse_theme(&theme_path)?, options);
+    converter.convert()?;
+    let highlight = converter.get_highlights();
+    assert!(!highlight.contains_key("TelescopeNormal"));
+
+    let options = ConversionOptions {
+        plugins: Some(vec!["not-a-plugin".to_string()]),
+        ..test_options(OutputFormat::Both)
+    };
+    let mut converter = ThemeConverter::new(utils::read_and_parse_theme(&theme_path)?, options);
+    assert!(converter.convert().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_gitsigns_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    assert_eq!(
+        highlight.get("GitSignsAdd").unwrap().fg,
+        Some("#40AD41".to_string())
+    );
+    assert_eq!(
+        highlight.get("GitSignsChangeNr").unwrap().fg,
+        Some("#A78CFA".to_string())
+    );
+    assert_eq!(
+        highlight.get("GitSignsDeleteInline").unwrap().bg,
+        Some("#f14c4c4C".to_string())
+    );
+    assert_eq!(
+        highlight.get("GitSignsCurrentLineBlame").unwrap().fg,
+        Some("#50567C".to_string())
+    );
+    assert_eq!(
+        highlight.get("diffAdded").unwrap().fg,
+        Some("#98C379".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_file_explorer_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    for group in ["NvimTreeNormal", "NeoTreeNormal"] {
+        let normal = highlight.get(group).unwrap();
+        assert_eq!(normal.fg, Some("#8E99B1".to_string()));
+        assert_eq!(normal.bg, Some("#21252B".to_string()));
+    }
+    assert_eq!(
+        highlight.get("NvimTreeIndentMarker").unwrap().fg,
+        Some("#454963".to_string())
+    );
+    assert_eq!(
+        highlight.get("NeoTreeRootName").unwrap().fg,
+        Some("#9DA5B3".to_string())
+    );
+    assert_eq!(
+        highlight.get("NeoTreeGitUntracked").unwrap().fg,
+        Some("#3EC141".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_completion_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    for group in ["CmpItemKindFunction", "BlinkCmpKindFunction"] {
+        assert_eq!(
+            highlight.get(group).unwrap().fg,
+            Some("#6495EE".to_string())
+        );
+    }
+    assert_eq!(
+        highlight.get("CmpItemKindClass").unwrap().fg,
+        Some("#56B7C3".to_string())
+    );
+    assert_eq!(
+        highlight.get("CmpItemAbbrMatch").unwrap().fg,
+        Some("#4480F4".to_string())
+    );
+    assert_eq!(
+        highlight.get("BlinkCmpMenu").unwrap().bg,
+        Some("#23272F".to_string())
+    );
+    assert_eq!(
+        highlight.get("BlinkCmpLabelMatch").unwrap().fg,
+        Some("#4480F4".to_string())
+    );
+    for group in ["CmpItemKind", "BlinkCmpKind"] {
+        assert!(highlight.get(group).unwrap().fg.is_some());
+    }
+    // 模板只用于 kind_groups，不应出现在组名中
+    assert!(highlight.keys().all(|group| !group.contains('{')));
+
+    Ok(())
+}
+
+#[test]
+fn test_lualine_theme_output() -> Result<()> {
+    let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    let options = ConversionOptions {
+        custom_name: Some("lualine_test".to_string()),
+        ..test_options(OutputFormat::Lua)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_lualine_test");
+    let files = converter.save_to_files(&output_dir)?;
+
+    let lualine_path = output_dir.join("lua/lualine/themes/lualine_test.lua");
+    assert!(files.contains(&lualine_path));
+
+    let content = std::fs::read_to_string(&lualine_path)?;
+    for mode in [
+        "normal", "insert", "visual", "replace", "command", "inactive",
+    ] {
+        assert!(content.contains(&format!("  {} = {{", mode)));
+    }
+    assert!(content.contains("c = { fg = \"#9da5b3\", bg = \"#21252b\" }"));
+    // replace 模式使用调试状态栏颜色
+    assert!(content.contains(
+        "  replace = {\n    a = { fg = \"#dde0e6\", bg = \"#6c2022\", gui = \"bold\" },"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_tabline_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    assert_eq!(
+        highlight.get("BufferLineFill").unwrap().bg,
+        Some("#21252B".to_string())
+    );
+    let selected = highlight.get("BufferLineBufferSelected").unwrap();
+    assert_eq!(selected.fg, Some("#d7dae0".to_string()));
+    assert_eq!(selected.bg, Some("#282c34".to_string()));
+    assert_eq!(
+        highlight.get("BufferLineBufferVisible").unwrap().fg,
+        Some("#d7dae0BF".to_string())
+    );
+    assert_eq!(
+        highlight.get("BufferLineModifiedSelected").unwrap().fg,
+        Some("#A78CFA".to_string())
+    );
+    assert_eq!(
+        highlight.get("BufferAlternate").unwrap().bg,
+        Some("#598DEF66".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rainbow_and_indent_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    let expected = [
+        ("RainbowDelimiterRed", "#838FA7"),
+        ("RainbowDelimiterYellow", "#CF68E1"),
+        ("RainbowDelimiterBlue", "#A78CFA"),
+        ("RainbowDelimiterOrange", "#FF6AB3"),
+        ("RainbowDelimiterGreen", "#56B7C3"),
+        ("RainbowDelimiterViolet", "#3ec141"),
+        ("RainbowDelimiterCyan", "#838FA7"),
+        ("IblIndent", "#454963"),
+        ("IblScope", "#FF9070BF"),
+        ("MiniIndentscopeSymbol", "#FF9070BF"),
+    ];
+    for (group, fg) in expected {
+        assert_eq!(highlight.get(group).unwrap().fg, Some(fg.to_string()));
+    }
+
+    // 每层参考线单独着色，缺少的层退回到第一层
+    let mut theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    theme.colors.insert(
+        "editorIndentGuide.background2".to_string(),
+        "#112233".to_string(),
+    );
+    theme.colors.insert(
+        "editorIndentGuide.activeBackground3".to_string(),
+        "#445566".to_string(),
+    );
+    let mut converter = ThemeConverter::new(theme, test_options(OutputFormat::Lua));
+    converter.convert()?;
+    let highlight = converter.get_highlights();
+    let expected = [
+        ("IblIndent1", "#454963"),
+        ("IblIndent2", "#112233"),
+        ("IblIndent6", "#454963"),
+        ("IblScope1", "#FF9070BF"),
+        ("IblScope3", "#445566"),
+    ];
+    for (group, fg) in expected {
+        assert_eq!(highlight.get(group).unwrap().fg, Some(fg.to_string()));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_popup_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    assert_eq!(
+        highlight.get("WhichKeyNormal").unwrap().bg,
+        Some("#21252B".to_string())
+    );
+    assert_eq!(
+        highlight.get("WhichKey").unwrap().fg,
+        Some("#E1E4EA".to_string())
+    );
+
+    let cmdline = highlight.get("NoiceCmdlinePopup").unwrap();
+    assert_eq!(cmdline.bg, Some("#282c34".to_string()));
+    assert_eq!(cmdline.fg, Some("#9DA5B3".to_string()));
+
+    let expected = [
+        ("NotifyERRORTitle", "#E51400"),
+        ("NotifyWARNIcon", "#FF8A4C"),
+        ("NotifyINFOBorder", "#4480F4"),
+        ("NotifyTRACETitle", "#4283F5"),
+    ];
+    for (group, fg) in expected {
+        assert_eq!(highlight.get(group).unwrap().fg, Some(fg.to_string()));
+    }
+    assert_eq!(
+        highlight.get("NotifyERRORBody").unwrap().bg,
+        Some("#21252b".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_dap_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    assert_eq!(
+        highlight.get("DapBreakpoint").unwrap().fg,
+        Some("#E51400".to_string())
+    );
+    assert_eq!(
+        highlight.get("DapLogPoint").unwrap().fg,
+        Some("#4480F4".to_string())
+    );
+    assert_eq!(
+        highlight.get("DapUIWatchesError").unwrap().fg,
+        Some("#E51400".to_string())
+    );
+    assert_eq!(
+        highlight.get("DapUIPlayPause").unwrap().bg,
+        Some("#21252B".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_symbol_kind_palette() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    for group in [
+        "CmpItemKindFunction",
+        "NavicIconsFunction",
+        "AerialFunctionIcon",
+        "OutlineFunction",
+        "TroubleIconFunction",
+    ] {
+        assert_eq!(
+            highlight.get(group).unwrap().fg,
+            Some("#6495EE".to_string())
+        );
+    }
+    assert_eq!(
+        highlight.get("NavicIconsNamespace").unwrap().fg,
+        Some("#E06C75".to_string())
+    );
+
+    // 没有 symbolIcon 键时退回到语法颜色
+    let highlight = convert_test_theme("test_catppuccin.json")?;
+    assert_eq!(
+        highlight.get("NavicIconsVariable").unwrap().fg,
+        Some("#cdd6f4".to_string())
+    );
+    assert!(!highlight.contains_key("NavicIconsFunction"));
+
+    Ok(())
+}
+
+#[test]
+fn test_mini_and_snacks_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    let expected = [
+        ("MiniStatuslineModeNormal", "#56B7C3"),
+        ("MiniStatuslineModeReplace", "#6c2022"),
+        ("MiniFilesNormal", "#21252B"),
+        ("MiniPickMatchCurrent", "#598DEF3F"),
+        ("MiniDiffOverAdd", "#4EC15033"),
+        ("SnacksDashboardNormal", "#282c34"),
+        ("SnacksPickerListCursorLine", "#598DEF3F"),
+    ];
+    for (group, bg) in expected {
+        assert_eq!(highlight.get(group).unwrap().bg, Some(bg.to_string()));
+    }
+    assert_eq!(
+        highlight.get("SnacksDashboardKey").unwrap().fg,
+        Some("#D365E5".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_jump_label_contrast() -> Result<()> {
+    // 可读的颜色保持不变
+    let highlight = convert_test_theme("test_catppuccin.json")?;
+    let label = highlight.get("FlashMatch").unwrap();
+    assert_eq!(label.fg, Some("#cdd6f4".to_string()));
+    assert_eq!(label.bg, Some("#3e5767".to_string()));
+
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    // 黄色查找背景上的浅灰文字会被加深
+    let current = highlight.get("FlashCurrent").unwrap();
+    assert_eq!(current.bg, Some("#F8F900".to_string()));
+    let current_fg = current.fg.clone().unwrap();
+    assert_ne!(current_fg, "#9da5b3");
+    assert_eq!(current_fg.len(), 7);
+
+    // 与编辑器背景过于接近的前景色会被提亮
+    let hop = highlight.get("HopNextKey2").unwrap().fg.clone().unwrap();
+    assert_ne!(hop, "#324464");
+
+    for group in ["FlashLabel", "LeapLabel", "HopNextKey", "HopNextKey2"] {
+        assert_label_contrast(&highlight, group);
+    }
+
+    // 没有 editorLink.activeForeground 时标签使用查找匹配背景
+    let highlight = convert_test_theme("test_catppuccin.json")?;
+    assert_label_contrast(&highlight, "FlashLabel");
+    assert_label_contrast(&highlight, "LeapLabel");
+    assert_eq!(
+        highlight.get("FlashLabel").unwrap().bg,
+        Some("#5e3f53".to_string())
+    );
+    assert_eq!(
+        highlight.get("LeapLabel").unwrap().bg,
+        Some("#5e3f53".to_string())
+    );
+
+    // 无法解析的标签颜色不会中断转换
+    let mut theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    theme.colors.insert(
+        "editorLink.activeForeground".to_string(),
+        "D365E5".to_string(),
+    );
+    let mut converter = ThemeConverter::new(theme, test_options(OutputFormat::Lua));
+    converter.convert()?;
+
+    Ok(())
+}
+
+/// 标签前景色与其背景 (没有时为 Normal 背景) 的对比度不低于 4.5
+fn assert_label_contrast(highlight: &HashMap<String, HighlightGroup>, group: &str) {
+    let normal_bg = highlight["Normal"].bg.clone().unwrap();
+    let label = &highlight[group];
+    let fg = color::normalize_color(label.fg.as_deref().unwrap(), &normal_bg).unwrap();
+    let bg = color::normalize_color(label.bg.as_deref().unwrap_or(&normal_bg), &normal_bg).unwrap();
+    let ratio = color::contrast_ratio(&fg, &bg).unwrap();
+    assert!(
+        ratio >= 4.5,
+        "{} contrast {} on {} is {}",
+        group,
+        fg,
+        bg,
+        ratio
+    );
+}
+
+#[test]
+fn test_word_occurrence_links() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    let expected = [
+        ("IlluminatedWordText", "LspReferenceText"),
+        ("IlluminatedWordRead", "LspReferenceRead"),
+        ("IlluminatedWordWrite", "LspReferenceWrite"),
+        ("MiniCursorword", "LspReferenceText"),
+    ];
+    for (group, target) in expected {
+        assert_eq!(highlight.get(group).unwrap().link, Some(target.to_string()));
+        assert!(highlight.contains_key(target));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_package_manager_and_dashboard_highlights() -> Result<()> {
+    let highlight = convert_test_theme("test_eva.json")?;
+
+    let h1 = highlight.get("LazyH1").unwrap();
+    assert_eq!(h1.bg, Some("#676E9599".to_string()));
+    assert_eq!(h1.fg, Some("#DCDFE5".to_string()));
+    assert_eq!(
+        highlight.get("MasonHeader").unwrap().bg,
+        Some("#676E95E5".to_string())
+    );
+    assert_eq!(
+        highlight.get("MasonHighlight").unwrap().fg,
+        Some("#4283F5".to_string())
+    );
+    for group in ["AlphaHeader", "DashboardHeader"] {
+        assert_eq!(
+            highlight.get(group).unwrap().fg,
+            Some("#4283F5".to_string())
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_plugin_directory_output() -> Result<()> {
+    let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    let options = ConversionOptions {
+        custom_name: Some("plugin_test".to_string()),
+        plugins: Some(vec!["telescope".to_string(), "nvim-tree".to_string()]),
+        vim_fallback: true,
+        ..test_options(OutputFormat::Plugin)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_plugin_test");
+    let files = converter.save_to_files(&output_dir)?;
+
+    for path in [
+        "colors/plugin_test.lua",
+        "colors/plugin_test.vim",
+        "lua/plugin_test/init.lua",
+        "lua/plugin_test/palette.lua",
+        "lua/plugin_test/groups/editor.lua",
+        "lua/plugin_test/groups/syntax.lua",
+        "lua/plugin_test/groups/treesitter.lua",
+        "lua/plugin_test/groups/integrations/telescope.lua",
+        "lua/plugin_test/groups/integrations/nvim-tree.lua",
+        "lua/lualine/themes/plugin_test.lua",
+        "README.md",
+        "LICENSE",
+    ] {
+        assert!(files.contains(&output_dir.join(path)), "missing {}", path);
+    }
+
+    let entry = std::fs::read_to_string(output_dir.join("colors/plugin_test.lua"))?;
+    assert!(entry.contains("require(\"plugin_test\").load()"));
+    let vim_entry = std::fs::read_to_string(output_dir.join("colors/plugin_test.vim"))?;
+    assert!(vim_entry.contains("let g:colors_name = \"plugin_test\""));
+
+    let init = std::fs::read_to_string(output_dir.join("lua/plugin_test/init.lua"))?;
+    assert!(init.contains("vim.g.colors_name = \"plugin_test\""));
+    assert!(init.contains("[\"nvim-tree\"] = true"));
+
+    let editor = std::fs::read_to_string(output_dir.join("lua/plugin_test/groups/editor.lua"))?;
+    let telescope = std::fs::read_to_string(
+        output_dir.join("lua/plugin_test/groups/integrations/telescope.lua"),
+    )?;
+    // 高亮组模块返回以调色板为参数的函数
+    for module in [&editor, &telescope] {
+        assert!(module.contains("return function(p)\n  return {\n"));
+        assert!(module.ends_with("  }\nend\n"));
+        assert!(!module.contains("require(\"plugin_test.palette\")"));
+    }
+    assert!(editor.contains("    Normal = { fg = p.fg, bg = p.bg },"));
+    assert!(telescope.contains("    TelescopeNormal = {"));
+    assert!(!editor.contains("TelescopeNormal"));
+
+    Ok(())
+}
+
+#[test]
+fn test_named_palette_output() -> Result<()> {
+    let theme = utils::read_and_parse_theme(&get_test_file_path("test_catppuccin.json"))?;
+    let options = ConversionOptions {
+        custom_name: Some("palette_test".to_string()),
+        plugins: Some(vec![]),
+        ..test_options(OutputFormat::Lua)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_palette_test");
+    converter.save_to_files(&output_dir)?;
+
+    let content = std::fs::read_to_string(output_dir.join("palette_test.lua"))?;
+    assert!(content.contains("M.palette = {\n  bg = \"#1e1e2e\",\n  fg = \"#cdd6f4\",\n"));
+    assert!(content.contains("local function base_groups(p)"));
+    assert!(content.contains("Normal = { fg = p.fg, bg = p.bg },"));
+
+    // 调色板同时写入独立模块
+    let palette = std::fs::read_to_string(output_dir.join("lua/palette_test/palette.lua"))?;
+    assert!(palette.contains("return {\n  bg = \"#1e1e2e\",\n  fg = \"#cdd6f4\",\n"));
+    assert!(palette.ends_with("}\n"));
+
+    // 高亮表中不再内联十六进制颜色
+    let tables = &content[content.find("local function base_groups(p)").unwrap()..];
+    assert!(!tables.contains("= \"#"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lua_user_override_hooks() -> Result<()> {
+    let theme = utils::read_and_parse_theme(&get_test_file_path("test_catppuccin.json"))?;
+    let options = ConversionOptions {
+        custom_name: Some("hooks_test".to_string()),
+        plugins: Some(vec!["telescope".to_string()]),
+        ..test_options(OutputFormat::Lua)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_hooks_test");
+    converter.save_to_files(&output_dir)?;
+
+    let content = std::fs::read_to_string(output_dir.join("hooks_test.lua"))?;
+    assert!(content.contains("function M.setup(opts)"));
+    assert!(content.contains("function M.load()"));
+    assert!(content.contains("  M.options.on_colors(p)\n"));
+    assert!(content.contains("  M.options.on_highlights(highlights, p)\n"));
+    assert!(
+        content.contains("  comments = { \"Comment\", \"@comment\", \"@comment.documentation\" },")
+    );
+    assert!(content.contains("  [\"telescope\"] = function(p)\n    return {\n"));
+
+    // 回调在设置高亮之前执行
+    let on_highlights = content
+        .find("M.options.on_highlights(highlights, p)")
+        .unwrap();
+    let hi_clear = content.find("vim.cmd('hi clear')").unwrap();
+    assert!(on_highlights < hi_clear);
+
+    Ok(())
+}
+
+#[test]
+fn test_transparent_background() -> Result<()> {
+    let output_dir = env::temp_dir().join("themex_transparent_test");
+    for format in [OutputFormat::Both, OutputFormat::Helix] {
+        let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+        let options = ConversionOptions {
+            custom_name: Some("transparent_test".to_string()),
+            transparent: true,
+            ..test_options(format)
+        };
+        let mut converter = ThemeConverter::new(theme, options);
+        converter.convert()?;
+        converter.save_to_files(&output_dir)?;
+    }
+
+    // Lua 保留背景色，由运行时按 `transparent` 选项清除
+    let lua = std::fs::read_to_string(output_dir.join("transparent_test.lua"))?;
+    assert!(lua.contains("  transparent = true,"));
+    assert!(lua.contains("    Normal = { fg = p.fg, bg = p.bg },"));
+    assert!(lua.contains("  if M.options.transparent then"));
+
+    // Vim 没有运行时选项，直接清除背景
+    let vim = std::fs::read_to_string(output_dir.join("transparent_test.vim"))?;
+    for group in ["Normal", "SignColumn", "StatusLine"] {
+        let line = vim
+            .lines()
+            .find(|line| line.starts_with(&format!("hi {} ", group)))
+            .unwrap();
+        assert!(line.contains("guibg=NONE"), "{}", line);
+    }
+    // 选区与搜索保留背景色
+    for group in ["Visual", "Search", "CursorLine"] {
+        let line = vim
+            .lines()
+            .find(|line| line.starts_with(&format!("hi {} ", group)))
+            .unwrap();
+        assert!(line.contains("guibg=#"), "{}", line);
+    }
+
+    // 其他导出格式不受影响
+    let helix = std::fs::read_to_string(output_dir.join("themes/transparent_test.toml"))?;
+    assert!(helix.contains("\"ui.background\" = { bg = \"bg\" }"));
+
+    Ok(())
+}
+
+#[test]
+fn test_helix_theme_output() -> Result<()> {
+    let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    let options = ConversionOptions {
+        custom_name: Some("helix_test".to_string()),
+        ..test_options(OutputFormat::Helix)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_helix_test");
+    let files = converter.save_to_files(&output_dir)?;
+
+    let helix_path = output_dir.join("themes/helix_test.toml");
+    assert_eq!(files, vec![helix_path.clone()]);
+
+    let content = std::fs::read_to_string(&helix_path)?;
+    let table: toml::Table = toml::from_str(&content)?;
+    let palette = table["palette"].as_table().unwrap();
+    assert_eq!(palette["bg"].as_str(), Some("#282c34"));
+    assert_eq!(palette["fg"].as_str(), Some("#9da5b3"));
+
+    assert_eq!(table["ui.background"]["bg"].as_str(), Some("bg"));
+    assert_eq!(table["ui.text"]["fg"].as_str(), Some("fg"));
+    // 作用域中引用的颜色都在调色板中
+    for key in ["ui.selection", "keyword.control", "comment"] {
+        let style = table[key].as_table().unwrap();
+        for value in style.values().filter_map(|value| value.as_str()) {
+            assert!(palette.contains_key(value), "{} -> {}", key, value);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_terminal_theme_outputs() -> Result<()> {
+    let outputs = [
+        (OutputFormat::Alacritty, "alacritty/term_test.toml"),
+        (OutputFormat::Kitty, "kitty/term_test.conf"),
+        (OutputFormat::Wezterm, "wezterm/term_test.toml"),
+        (OutputFormat::Ghostty, "ghostty/term_test"),
+        (OutputFormat::Foot, "foot/term_test.ini"),
+    ];
+
+    for (format, path) in outputs {
+        let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+        let options = ConversionOptions {
+            custom_name: Some("term_test".to_string()),
+            ..test_options(format)
+        };
+
+        let mut converter = ThemeConverter::new(theme, options);
+        converter.convert()?;
+        let output_dir = env::temp_dir().join(format!("themex_terminal_test_{:?}", format));
+        let files = converter.save_to_files(&output_dir)?;
+        assert_eq!(files, vec![output_dir.join(path)]);
+
+        let content = std::fs::read_to_string(output_dir.join(path))?;
+        match format {
+            OutputFormat::Alacritty => {
+                let table: toml::Table = toml::from_str(&content)?;
+                let colors = &table["colors"];
+                assert_eq!(colors["primary"]["background"].as_str(), Some("#282c34"));
+                assert_eq!(colors["normal"]["red"].as_str(), Some("#e51400"));
+                assert_eq!(colors["bright"]["red"].as_str(), Some("#f14c4c"));
+                assert_eq!(colors["cursor"]["cursor"].as_str(), Some("#ff9070"));
+            }
+            OutputFormat::Wezterm => {
+                let table: toml::Table = toml::from_str(&content)?;
+                assert_eq!(table["colors"]["ansi"].as_array().unwrap().len(), 8);
+                assert_eq!(table["colors"]["brights"].as_array().unwrap().len(), 8);
+                assert_eq!(table["metadata"]["name"].as_str(), Some("term_test"));
+            }
+            OutputFormat::Kitty => {
+                assert!(content.contains("background #282c34\n"));
+                assert!(content.contains("color15 "));
+            }
+            OutputFormat::Ghostty => {
+                assert!(content.contains("palette = 1=#e51400\n"));
+                assert!(content.contains("selection-background = #394e75\n"));
+            }
+            _ => {
+                assert!(content.contains("[colors]\nforeground=b0b7c3\nbackground=282c34\n"));
+                assert!(content.contains("bright7="));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_zed_theme_output() -> Result<()> {
+    let mut theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    // 简写颜色需展开
+    theme
+        .colors
+        .insert("statusBar.background".to_string(), "#2AB".to_string());
+    theme
+        .colors
+        .insert("focusBorder".to_string(), "#4480F480".to_string());
+    theme
+        .colors
+        .insert("panel.border".to_string(), "#fff8".to_string());
+    let options = ConversionOptions {
+        custom_name: Some("Zed Test".to_string()),
+        ..test_options(OutputFormat::Zed)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_zed_test");
+    converter.save_to_files(&output_dir)?;
+
+    let content = std::fs::read_to_string(output_dir.join("themes/zed_test.json"))?;
+    let family: serde_json::Value = serde_json::from_str(&content)?;
+    // 文件名使用处理过的名称，主题名保持原样
+    assert_eq!(family["name"], "Zed Test");
+    assert_eq!(family["themes"][0]["name"], "Zed Test");
+
+    let theme = &family["themes"][0];
+    assert_eq!(theme["appearance"], "dark");
+    let style = &theme["style"];
+    assert_eq!(style["editor.background"], "#282c34");
+    assert_eq!(style["editor.gutter.background"], "#282c34");
+    assert_eq!(style["status_bar.background"], "#22aabb");
+    assert_eq!(style["border.focused"], "#4480f480");
+    assert_eq!(style["border.variant"], "#ffffff88");
+    // 界面颜色保留透明度
+    assert_eq!(style["element.selected"], "#598def66");
+    assert!(style["players"][0]["cursor"].is_string());
+    assert!(style["syntax"]["comment"]["color"].is_string());
+    // markup.bold 与 markup.italic 的 fontStyle
+    assert_eq!(style["syntax"]["emphasis.strong"]["font_weight"], 700);
+    assert_eq!(style["syntax"]["emphasis"]["font_style"], "italic");
+    assert!(style["syntax"]["keyword"]["color"].is_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_emacs_theme_output() -> Result<()> {
+    let mut theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    theme
+        .token_colors
+        .push(serde_json::from_value(serde_json::json!({
+            "scope": "comment",
+            "settings": { "foreground": "#676E95", "fontStyle": "bold italic" }
+        }))?);
+    let options = ConversionOptions {
+        custom_name: Some("emacs_test".to_string()),
+        ..test_options(OutputFormat::Emacs)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_emacs_test");
+    converter.save_to_files(&output_dir)?;
+
+    let content = std::fs::read_to_string(output_dir.join("emacs-test-theme.el"))?;
+    assert!(content.contains("(deftheme emacs-test"));
+    assert!(content.contains("(custom-theme-set-faces\n 'emacs-test"));
+    assert!(content.contains("'(default ((t (:foreground \"#9da5b3\" :background \"#282c34\"))))"));
+    assert!(content.contains("'(mode-line ((t (:foreground"));
+    assert!(content.contains("'(font-lock-comment-face ((t (:foreground"));
+    // tokenColors 中的 fontStyle 需保留
+    let comment_face = content
+        .lines()
+        .find(|line| line.contains("'(font-lock-comment-face "))
+        .expect("missing font-lock-comment-face");
+    assert!(comment_face.contains(":weight bold"), "{}", comment_face);
+    assert!(comment_face.contains(":slant italic"), "{}", comment_face);
+    assert!(content.contains("'(tree-sitter-hl-face:keyword ((t (:foreground"));
+    assert!(content.contains("(provide-theme 'emacs-test)"));
+    // 括号需配对
+    assert_eq!(content.matches('(').count(), content.matches(')').count());
+
+    Ok(())
+}
+
+#[test]
+fn test_sublime_theme_output() -> Result<()> {
+    let mut theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+    // 排除语法
+    theme
+        .token_colors
+        .push(serde_json::from_value(serde_json::json!({
+            "scope": ["source  -comment", "text.html - (meta.embedded, string)"],
+            "settings": { "foreground": "#ff0000" }
+        }))?);
+    let options = ConversionOptions {
+        custom_name: Some("sublime_test".to_string()),
+        ..test_options(OutputFormat::Sublime)
+    };
+
+    let mut converter = ThemeConverter::new(theme, options);
+    converter.convert()?;
+    let output_dir = env::temp_dir().join("themex_sublime_test");
+    converter.save_to_files(&output_dir)?;
+
+    let content = std::fs::read_to_string(output_dir.join("sublime_test.sublime-color-scheme"))?;
+    let scheme: serde_json::Value = serde_json::from_str(&content)?;
+    assert_eq!(scheme["name"], "Eva Dark");
+    assert_eq!(scheme["globals"]["background"], "#282c34");
+    // 选区颜色与背景混合
+    assert_eq!(scheme["globals"]["selection"], "#394e75");
+    let rules = scheme["rules"].as_array().unwrap();
+    assert!(!rules.is_empty());
+    assert!(rules.iter().all(|rule| rule["scope"].is_string()));
+    assert!(rules.iter().any(|rule| rule["font_style"] == "bold"));
+    assert!(
+        rules
+            .iter()
+            .any(|rule| rule["scope"] == "source - comment, text.html - (meta.embedded, string)")
+    );
+
+    let tm_theme = std::fs::read_to_string(output_dir.join("sublime_test.tmTheme"))?;
+    assert!(tm_theme.starts_with("<?xml"));
+    assert!(tm_theme.contains("<key>selection</key>\n\t\t\t\t<string>#394e75</string>"));
+    assert_eq!(tm_theme.matches("<key>scope</key>").count(), rules.len());
+
+    Ok(())
+}
+
+#[test]
+fn test_theme_include() -> Result<()> {
+    let dir = env::temp_dir().join("themex_include_test");
+    utils::write_file(
+        &dir.join("base/parent.json"),
+        r##"{
+            "name": "Parent",
+            "type": "dark",
+            "semanticHighlighting": true,
+            "colors": { "editor.background": "#000000", "editor.foreground": "#ffffff" },
+            "tokenColors": [
+                { "scope": "comment", "settings": { "foreground": "#808080" } }
+            ]
+        }"##,
+    )?;
+    utils::write_file(
+        &dir.join("child.json"),
+        r##"{
+            "name": "Child",
+            "include": "./base/parent.json",
+            "colors": { "editor.background": "#101010" },
+            "tokenColors": [
+                { "scope": "keyword", "settings": { "foreground": "#ff0000" } }
+            ]
+        }"##,
+    )?;
+
+    let theme = utils::read_and_parse_theme(&dir.join("child.json"))?;
+    assert_eq!(theme.name, "Child");
+    assert_eq!(theme.theme_type, "dark");
+    assert_eq!(theme.colors["editor.background"], "#101010");
+    assert_eq!(theme.colors["editor.foreground"], "#ffffff");
+    // 父主题的 tokenColors 在前
+    assert_eq!(theme.token_colors.len(), 2);
+    assert_eq!(
+        theme.token_colors[1].settings.foreground.as_deref(),
+        Some("#ff0000")
+    );
+
+    utils::write_file(
+        &dir.join("missing.json"),
+        r#"{ "name": "Missing", "include": "./nope.json" }"#,
+    )?;
+    let err = utils::read_and_parse_theme(&dir.join("missing.json")).unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("Failed to resolve include \"./nope.json\""),
+        "{:#}",
+        err
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tool_snippet_outputs() -> Result<()> {
+    let output_dir = env::temp_dir().join("themex_tools_test");
+    for format in [OutputFormat::Tmux, OutputFormat::Fzf, OutputFormat::Lazygit] {
+        let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+        let options = ConversionOptions {
+            custom_name: Some("tools_test".to_string()),
+            ..test_options(format)
+        };
+        let mut converter = ThemeConverter::new(theme, options);
+        converter.convert()?;
+        converter.save_to_files(&output_dir)?;
+    }
+
+    let tmux = std::fs::read_to_string(output_dir.join("tmux/tools_test.conf"))?;
+    assert!(tmux.contains("set -g status-style \"fg=#9da5b3,bg=#21252b\""));
+    assert!(tmux.contains("set -g pane-active-border-style \"fg=#4480f4\""));
+
+    let fzf = std::fs::read_to_string(output_dir.join("fzf/tools_test.sh"))?;
+    assert!(fzf.contains(
+        "export FZF_DEFAULT_OPTS=\"$FZF_DEFAULT_OPTS --color=fg:#9da5b3,bg:#282c34,hl:#5889ea,"
+    ));
+
+    let lazygit = std::fs::read_to_string(output_dir.join("lazygit/tools_test.yml"))?;
+    assert!(
+        lazygit.contains(
+            "gui:\n  theme:\n    activeBorderColor:\n      - \"#4480f4\"\n      - bold\n"
+        )
+    );
+    assert!(lazygit.contains("    selectedLineBgColor:\n      - \"#"));
+
+    Ok(())
+}
+
+#[test]
+fn test_generated_lua_is_well_formed() -> Result<()> {
+    let all_plugins: Vec<String> = plugins::integration_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    let output_dir = env::temp_dir().join("themex_lua_shape_test");
+    for format in [OutputFormat::Lua, OutputFormat::Plugin] {
+        let theme = utils::read_and_parse_theme(&get_test_file_path("test_eva.json"))?;
+        let options = ConversionOptions {
+            custom_name: Some("shape_test".to_string()),
+            plugins: Some(all_plugins.clone()),
+            ..test_options(format)
+        };
+        let mut converter = ThemeConverter::new(theme, options);
+        converter.convert()?;
+        converter.save_to_files(&output_dir)?;
+    }
+
+    let lua = std::fs::read_to_string(output_dir.join("shape_test.lua"))?;
+    assert_valid_lua_keys(&lua);
+    assert!(lua.contains("    CmpItemKind = {"));
+
+    let groups_dir = output_dir.join("lua/shape_test/groups");
+    let mut modules = vec![
+        groups_dir.join("editor.lua"),
+        groups_dir.join("syntax.lua"),
+        groups_dir.join("treesitter.lua"),
+    ];
+    for name in &all_plugins {
+        modules.push(
+            groups_dir
+                .join("integrations")
+                .join(format!("{}.lua", name)),
+        );
+    }
+    for module in modules {
+        let content = std::fs::read_to_string(&module)?;
+        assert_valid_lua_keys(&content);
+        let body = content
+            .split_once("\n\n")
+            .map(|(_, body)| body)
+            .unwrap_or_default();
+        assert!(
+            body.starts_with("return function(p)\n") && body.ends_with("end\n"),
+            "{:?} does not return a function",
+            module
+        );
+    }
+
+    Ok(())
+}